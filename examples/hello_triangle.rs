@@ -43,9 +43,9 @@ fn example() -> Result<()> {
 
     glfw::set_error_callback(Some(glfw_error_callback));
 
-    glfw::window_hint(glfw::CONTEXT_VERSION_MAJOR, 3);
-    glfw::window_hint(glfw::CONTEXT_VERSION_MINOR, 3);
-    glfw::window_hint(glfw::OPENGL_PROFILE, glfw::OPENGL_CORE_PROFILE);
+    glfw::window_hint(glfw::WindowHint::ContextVersionMajor(3));
+    glfw::window_hint(glfw::WindowHint::ContextVersionMinor(3));
+    glfw::window_hint(glfw::WindowHint::OpenGlProfile(glfw::OpenGlProfile::Core));
 
     let window = glfw::create_window(
         INITIAL_WIDTH,
@@ -89,6 +89,10 @@ fn example() -> Result<()> {
     while !glfw::window_should_close(window) {
         glfw::poll_events();
 
+        if glfw::get_key(window, glfw::Key::Escape) == glfw::Action::Press {
+            glfw::set_window_should_close(window, true);
+        }
+
         gl::clear_color(0.2, 0.3, 0.3, 1.0);
         gl::clear(gl::COLOR_BUFFER_BIT);
 
@@ -54,9 +54,9 @@ fn example() -> Result<()> {
 
     glfw::set_error_callback(Some(glfw_error_callback));
 
-    glfw::window_hint(glfw::CONTEXT_VERSION_MAJOR, 3);
-    glfw::window_hint(glfw::CONTEXT_VERSION_MINOR, 3);
-    glfw::window_hint(glfw::OPENGL_PROFILE, glfw::OPENGL_CORE_PROFILE);
+    glfw::window_hint(glfw::WindowHint::ContextVersionMajor(3));
+    glfw::window_hint(glfw::WindowHint::ContextVersionMinor(3));
+    glfw::window_hint(glfw::WindowHint::OpenGlProfile(glfw::OpenGlProfile::Core));
 
     let window = glfw::create_window(
         INITIAL_WIDTH,
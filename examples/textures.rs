@@ -63,9 +63,9 @@ fn example() -> Result<()> {
 
     glfw::set_error_callback(Some(glfw_error_callback));
 
-    glfw::window_hint(glfw::CONTEXT_VERSION_MAJOR, 3);
-    glfw::window_hint(glfw::CONTEXT_VERSION_MINOR, 3);
-    glfw::window_hint(glfw::OPENGL_PROFILE, glfw::OPENGL_CORE_PROFILE);
+    glfw::window_hint(glfw::WindowHint::ContextVersionMajor(3));
+    glfw::window_hint(glfw::WindowHint::ContextVersionMinor(3));
+    glfw::window_hint(glfw::WindowHint::OpenGlProfile(glfw::OpenGlProfile::Core));
 
     let window = glfw::create_window(
         INITIAL_WIDTH,
@@ -128,7 +128,7 @@ fn example() -> Result<()> {
         gl::LINEAR_MIPMAP_LINEAR.into(),
     );
     gl::tex_parameter(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR.into());
-    gl::tex_image_2d(gl::TEXTURE_2D, 0, gl::RGB, &image, gl::RGB);
+    gl::tex_image_2d(gl::TEXTURE_2D, 0, gl::RGB, &image, gl::RGB)?;
     gl::generate_mipmap(gl::TEXTURE_2D);
 
     while !glfw::window_should_close(window) {
@@ -2,9 +2,9 @@
 //!
 //! [Transformations]: https://learnopengl.com/Getting-started/Transformations
 
-use std::{mem, process};
+use std::{error, mem, process};
 
-use hitchcock::{gl, glfw, stb_image, Mat4, Result};
+use hitchcock::{gl, glfw, stb_image, Context, Mat4, Result};
 
 /// Initial width of the window.
 const INITIAL_WIDTH: i32 = 800;
@@ -28,33 +28,25 @@ const VERTICES: [f32; 20] = [
     1.0, 1.0, // Texture coordinates.
 ];
 
-/// Defines the layout of the vertex data.
-struct VertexLayout {
-    size: usize,
-    typ: u32,
-    normalized: bool,
-    stride: usize,
-    pointer: usize,
-}
-
 /// Vertex data layout.
-const LAYOUTS: [VertexLayout; 2] = [
-    VertexLayout {
+const ATTRIBS: [gl::VertexAttrib; 2] = [
+    gl::VertexAttrib {
         size: 3,
         typ: gl::FLOAT,
         normalized: false,
-        stride: 5 * mem::size_of::<f32>(),
-        pointer: 0,
+        offset: 0,
     },
-    VertexLayout {
+    gl::VertexAttrib {
         size: 2,
         typ: gl::FLOAT,
         normalized: false,
-        stride: 5 * mem::size_of::<f32>(),
-        pointer: 3 * mem::size_of::<f32>(),
+        offset: 3 * mem::size_of::<f32>(),
     },
 ];
 
+/// Size of every vertex in bytes.
+const STRIDE: usize = 5 * mem::size_of::<f32>();
+
 /// Elements.
 const INDICES: [u32; 6] = [0, 1, 3, 3, 2, 0];
 
@@ -109,51 +101,54 @@ fn run() -> Result<()> {
         "LearnOpenGL: Transformations",
     )?;
     glfw::make_context_current(window);
+    gl::load_all()?;
     glfw::set_framebuffer_size_callback(window, Some(glfw_framebuffer_size_callback));
 
     gl::enable(gl::DEBUG_OUTPUT);
     gl::debug_message_callback(gl_debug_callback);
 
-    let shader_program = build_shader_program(VERTEX_SHADER_SOURCE, FRAGMENT_SHADER_SOURCE)?;
+    let mut shader_program =
+        gl::ShaderProgram::from_sources(VERTEX_SHADER_SOURCE, FRAGMENT_SHADER_SOURCE, None)
+            .context("building shader program")?;
 
-    let (vao, vbo, ebo) = build_buffers(&VERTICES, &LAYOUTS, &INDICES);
+    let mesh = gl::Mesh::new(&VERTICES, &ATTRIBS, STRIDE, Some(&INDICES));
 
-    let image = stb_image::Image::load_from_memory(WALL_JPG)?;
-    let to_wall = build_texture(shader_program, "uTexture1", 0, &image, gl::RGB)?;
+    let image = stb_image::Image::load_from_memory(WALL_JPG).context("loading wall.jpg")?;
+    let to_wall = build_texture(&image, gl::RGB)?;
     stb_image::set_flip_vertically_on_load(true);
-    let image = stb_image::Image::load_from_memory(AWESOMEFACE_PNG)?;
-    let to_face = build_texture(shader_program, "uTexture2", 1, &image, gl::RGBA)?;
+    let image =
+        stb_image::Image::load_from_memory(AWESOMEFACE_PNG).context("loading awesomeface.png")?;
+    let to_face = build_texture(&image, gl::RGBA)?;
 
-    let uniform_location = gl::get_uniform_location(shader_program, "uTransform")?;
     let transform_tl = Mat4::translate(-0.5, 0.5, 0.0) * Mat4::scale(0.5, 0.5, 0.0);
     let transform_br = Mat4::translate(0.5, -0.5, 0.0) * Mat4::scale(0.5, 0.5, 0.0);
 
+    let mut texture_units = gl::TextureUnits::new();
+
     while !glfw::window_should_close(window) {
         glfw::poll_events();
 
         gl::clear_color(0.2, 0.3, 0.3, 1.0);
         gl::clear(gl::COLOR_BUFFER_BIT);
 
-        gl::use_program(shader_program);
+        shader_program.bind();
 
-        gl::active_texture(gl::TEXTURE0);
-        gl::bind_texture(gl::TEXTURE_2D, to_wall);
-        gl::active_texture(gl::TEXTURE0 + 1);
-        gl::bind_texture(gl::TEXTURE_2D, to_face);
+        let program = shader_program.program();
+        texture_units.reset();
+        texture_units.bind(program, "uTexture1", gl::TEXTURE_2D, to_wall)?;
+        texture_units.bind(program, "uTexture2", gl::TEXTURE_2D, to_face)?;
 
-        gl::bind_vertex_array(vao);
-        gl::uniform(uniform_location, transform_tl.into());
-        gl::draw_elements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, 0);
-        gl::uniform(uniform_location, transform_br.into());
-        gl::draw_elements(gl::TRIANGLES, 6, gl::UNSIGNED_INT, 0);
+        shader_program.set_uniform("uTransform", transform_tl)?;
+        mesh.draw();
+        shader_program.set_uniform("uTransform", transform_br)?;
+        mesh.draw();
 
         glfw::swap_buffers(window);
     }
 
-    gl::delete_vertex_arrays(&[vao]);
-    gl::delete_buffers(&[vbo, ebo]);
+    mesh.delete();
     gl::delete_textures(&[to_wall, to_face]);
-    gl::delete_program(shader_program);
+    shader_program.delete();
 
     glfw::terminate();
 
@@ -162,76 +157,15 @@ fn run() -> Result<()> {
 
 /// Creates a system window.
 fn build_window(width: i32, height: i32, title: &str) -> Result<glfw::Window> {
-    glfw::window_hint(glfw::CONTEXT_VERSION_MAJOR, 3);
-    glfw::window_hint(glfw::CONTEXT_VERSION_MINOR, 3);
-    glfw::window_hint(glfw::OPENGL_PROFILE, glfw::OPENGL_CORE_PROFILE);
+    glfw::window_hint(glfw::WindowHint::ContextVersionMajor(3));
+    glfw::window_hint(glfw::WindowHint::ContextVersionMinor(3));
+    glfw::window_hint(glfw::WindowHint::OpenGlProfile(glfw::OpenGlProfile::Core));
     let window = glfw::create_window(width, height, title, None, None)?;
     Ok(window)
 }
 
-/// Compiles and links a shader program.
-fn build_shader_program(vertex_shader_src: &str, fragment_shader_src: &str) -> Result<gl::Program> {
-    let vertex_shader = gl::create_shader(gl::VERTEX_SHADER);
-    gl::shader_source(vertex_shader, &[vertex_shader_src])?;
-    gl::compile_shader(vertex_shader);
-
-    let fragment_shader = gl::create_shader(gl::FRAGMENT_SHADER);
-    gl::shader_source(fragment_shader, &[fragment_shader_src])?;
-    gl::compile_shader(fragment_shader);
-
-    let shader_program = gl::create_program();
-    gl::attach_shader(shader_program, vertex_shader);
-    gl::attach_shader(shader_program, fragment_shader);
-    gl::link_program(shader_program);
-    gl::delete_shader(vertex_shader);
-    gl::delete_shader(fragment_shader);
-
-    Ok(shader_program)
-}
-
-/// Sets up the vertex buffers. Returns a tuple of the form
-/// `(vertex_arrays, vertex_buffers, element_buffers)`.
-fn build_buffers(
-    vertices: &[f32],
-    layouts: &[VertexLayout],
-    indices: &[u32],
-) -> (gl::VertexArray, gl::Buffer, gl::Buffer) {
-    let vaos = gl::gen_vertex_arrays(1);
-    let vbos = gl::gen_buffers(1);
-    let ebos = gl::gen_buffers(1);
-
-    gl::bind_vertex_array(vaos[0]);
-    gl::bind_buffer(gl::ARRAY_BUFFER, vbos[0]);
-    gl::buffer_data(gl::ARRAY_BUFFER, vertices, gl::STATIC_DRAW);
-    gl::bind_buffer(gl::ELEMENT_ARRAY_BUFFER, ebos[0]);
-    gl::buffer_data(gl::ELEMENT_ARRAY_BUFFER, indices, gl::STATIC_DRAW);
-
-    for (i, layout) in layouts.iter().enumerate() {
-        gl::vertex_attrib_pointer(
-            i as u32,
-            layout.size,
-            layout.typ,
-            layout.normalized,
-            layout.stride,
-            layout.pointer,
-        );
-        gl::enable_vertex_attrib_array(i as u32);
-    }
-
-    gl::bind_buffer(gl::ARRAY_BUFFER, gl::Buffer::zero());
-    gl::bind_vertex_array(gl::VertexArray::zero());
-
-    (vaos[0], vbos[0], ebos[0])
-}
-
 /// Sets up a 2D texture.
-fn build_texture(
-    shader_program: gl::Program,
-    texture_uniform: &str,
-    texture_unit: i32,
-    image: &stb_image::Image,
-    image_format: u32,
-) -> Result<gl::Texture> {
+fn build_texture(image: &stb_image::Image, image_format: u32) -> Result<gl::Texture> {
     let tos = gl::gen_textures(1);
 
     gl::bind_texture(gl::TEXTURE_2D, tos[0]);
@@ -243,13 +177,8 @@ fn build_texture(
         gl::LINEAR_MIPMAP_LINEAR.into(),
     );
     gl::tex_parameter(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR.into());
-    gl::tex_image_2d(gl::TEXTURE_2D, 0, gl::RGB, image, image_format);
+    gl::tex_image_2d(gl::TEXTURE_2D, 0, gl::RGB, image, image_format)?;
     gl::generate_mipmap(gl::TEXTURE_2D);
-    gl::use_program(shader_program);
-    gl::uniform(
-        gl::get_uniform_location(shader_program, texture_uniform)?,
-        texture_unit.into(),
-    );
 
     Ok(tos[0])
 }
@@ -278,6 +207,11 @@ fn gl_debug_callback(
 fn main() {
     run().unwrap_or_else(|err| {
         println!("Error: {err}");
+        let mut source = error::Error::source(&err);
+        while let Some(err) = source {
+            println!("Caused by: {err}");
+            source = err.source();
+        }
         process::exit(1);
     });
 }
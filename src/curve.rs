@@ -0,0 +1,97 @@
+//! One-dimensional curves for easing and envelopes.
+
+/// Curve key point.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CurvePoint {
+    /// Position along the X axis.
+    pub x: f32,
+
+    /// Value at `x`.
+    pub y: f32,
+
+    /// Slope of the curve at `x`.
+    pub tangent: f32,
+}
+
+impl CurvePoint {
+    /// Creates a key point.
+    pub fn new(x: f32, y: f32, tangent: f32) -> CurvePoint {
+        CurvePoint { x, y, tangent }
+    }
+}
+
+/// Curve defined by a set of key points, which are interpolated
+/// using cubic Hermite splines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Curve {
+    points: Vec<CurvePoint>,
+}
+
+impl Curve {
+    /// Creates a curve from the provided key points.
+    pub fn new(mut points: Vec<CurvePoint>) -> Curve {
+        points.sort_by(|a, b| a.x.total_cmp(&b.x));
+        Curve { points }
+    }
+
+    /// Returns a linear ramp from `(0, 0)` to `(1, 1)`.
+    pub fn linear() -> Curve {
+        Curve::new(vec![
+            CurvePoint::new(0.0, 0.0, 1.0),
+            CurvePoint::new(1.0, 1.0, 1.0),
+        ])
+    }
+
+    /// Returns the key points sorted by their X coordinate.
+    pub fn points(&self) -> &[CurvePoint] {
+        &self.points
+    }
+
+    /// Returns a mutable reference to the key point `i`. The caller
+    /// must keep the points sorted by their X coordinate.
+    pub fn point_mut(&mut self, i: usize) -> Option<&mut CurvePoint> {
+        self.points.get_mut(i)
+    }
+
+    /// Inserts a key point. It returns its index.
+    pub fn insert(&mut self, point: CurvePoint) -> usize {
+        let i = self.points.partition_point(|p| p.x < point.x);
+        self.points.insert(i, point);
+        i
+    }
+
+    /// Removes the key point `i`.
+    pub fn remove(&mut self, i: usize) -> CurvePoint {
+        self.points.remove(i)
+    }
+
+    /// Evaluates the curve at `x`. Outside of the range defined by
+    /// the key points, the value of the closest point is returned. An
+    /// empty curve evaluates to zero and NaN evaluates to the value of
+    /// the first point.
+    pub fn sample(&self, x: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 0.0;
+        };
+        if x.is_nan() || x <= first.x {
+            return first.y;
+        }
+        if x >= last.x {
+            return last.y;
+        }
+
+        let i = self.points.partition_point(|p| p.x <= x);
+        let (p0, p1) = (self.points[i - 1], self.points[i]);
+        let h = p1.x - p0.x;
+        if h <= 0.0 {
+            return p1.y;
+        }
+        let t = (x - p0.x) / h;
+        let (t2, t3) = (t * t, t * t * t);
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+        h00 * p0.y + h10 * h * p0.tangent + h01 * p1.y + h11 * h * p1.tangent
+    }
+}
@@ -1,17 +1,22 @@
 //! OpenGL bindings.
 
 use std::{
+    backtrace::Backtrace,
+    collections::HashMap,
     error,
     ffi::{c_void, CStr, CString, NulError},
-    fmt, mem, ptr, result,
-    sync::Mutex,
+    fmt, mem, ptr, result, slice,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
 };
 
-use crate::{macros::define_enum, stb_image, Mat4, Vec4};
+use crate::{glfw, macros::define_enum, stb_image, Context, Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
 
 #[allow(non_snake_case, clippy::too_many_arguments)]
 mod ffi {
-    use std::ffi::{c_char, c_float, c_int, c_uchar, c_uint, c_void};
+    use std::ffi::{c_char, c_double, c_float, c_int, c_uchar, c_uint, c_void};
 
     pub type GLenum = c_uint;
     pub type GLboolean = c_uchar;
@@ -19,9 +24,14 @@ mod ffi {
     pub type GLchar = c_char;
     pub type GLint = c_int;
     pub type GLuint = c_uint;
+    pub type GLubyte = c_uchar;
     pub type GLsizei = c_int;
     pub type GLsizeiptr = usize;
+    pub type GLintptr = isize;
     pub type GLfloat = c_float;
+    pub type GLdouble = c_double;
+    pub type GLuint64 = u64;
+    pub type GLsync = *const c_void;
 
     macro_rules! glfn {
         ($name:ident, $once:ident, $ret:ty $(, $pname:ident: $ptype:ty)*) => {
@@ -29,77 +39,447 @@ mod ffi {
             pub unsafe fn $name($($pname: $ptype),*) -> $ret {
                 let f = $once.get_or_init(|| unsafe {
                     std::mem::transmute::<crate::glfw::GlProc, fn($($ptype),*) -> $ret>(
-                        crate::glfw::get_proc_address(stringify!($name)).expect("failed to get OpenGL proc address"),
+                        crate::glfw::get_proc_address(stringify!($name))
+                            .unwrap_or_else(|_| panic!("failed to get OpenGL proc address: {}", stringify!($name))),
                     )
                 });
-                f($($pname),*)
+                let ret = f($($pname),*);
+                if super::PENDING_PANIC.load(std::sync::atomic::Ordering::Relaxed) {
+                    super::pending_panic();
+                }
+                if stringify!($name) != "glGetError"
+                    && super::ERROR_CHECKS.load(std::sync::atomic::Ordering::Relaxed)
+                {
+                    super::check_error(stringify!($name), &[$(&$pname as &dyn std::fmt::Debug),*]);
+                }
+                ret
             }
         }
     }
 
-    glfn![glActiveTexture, GL_ACTIVE_TEXTURE, (), texture: GLenum];
-    glfn![glAttachShader, GL_ATTACH_SHADER, (), program: GLuint, shader: GLuint];
-    glfn![glBindBuffer, GL_BIND_BUFFER, (), target: GLenum, buffer: GLuint];
-    glfn![glBindTexture, GL_BIND_TEXTURE, (), target: GLenum, texture: GLuint];
-    glfn![glBindVertexArray, GL_BIND_VERTEX_ARRAY, (), array: GLuint];
-    glfn![glBufferData, GL_BUFFER_DATA, (), target: GLenum, size: GLsizeiptr, data: *const c_void, usage: GLenum];
-    glfn![glClear, GL_CLEAR, (), mask: GLbitfield];
-    glfn![glClearColor, GL_CLEAR_COLOR, (), red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat];
-    glfn![glCompileShader, GL_COMPILE_SHADER, (), shader: GLuint];
-    glfn![glCreateProgram, GL_CREATE_PROGRAM, GLuint];
-    glfn![glCreateShader, GL_CREATE_SHADER, GLuint, typ: GLenum];
-    glfn![glDebugMessageCallback, GL_DEBUG_MESSAGE_CALLBACK, (), callback: *const c_void, user_param: *const c_void];
-    glfn![glDeleteBuffers, GL_DELETE_BUFFERS, (), n: GLsizei, buffers: *const GLuint];
-    glfn![glDeleteProgram, GL_DELETE_PROGRAM, (), program: GLuint];
-    glfn![glDeleteShader, GL_DELETE_SHADER, (), shader: GLuint];
-    glfn![glDeleteTextures, GL_DELETE_TEXTURES, (), n: GLsizei, textures: *const GLuint];
-    glfn![glDeleteVertexArrays, GL_DELETE_VERTEX_ARRAYS, (), n: GLsizei, arrays: *const GLuint];
-    glfn![glDrawArrays, GL_DRAW_ARRAYS, (), mode: GLenum, first: GLint, count: GLsizei];
-    glfn![glDrawElements, GL_DRAW_ELEMENTS, (), mode: GLenum, count: GLsizei, typ: GLenum, indices: *const c_void];
-    glfn![glEnable, GL_ENABLE, (), cap: GLenum];
-    glfn![glEnableVertexAttribArray, GL_ENABLE_VERTEX_ATTRIB_ARRAY, (), index: GLuint];
-    glfn![glGenBuffers, GL_GEN_BUFFERS, (), n: GLsizei, buffers: *mut GLuint];
-    glfn![glGenTextures, GL_GEN_TEXTURES, (), n: GLsizei, textures: *mut GLuint];
-    glfn![glGenVertexArrays, GL_GEN_VERTEX_ARRAYS, (), n: GLsizei, arrays: *mut GLuint];
-    glfn![glGenerateMipmap, GL_GENERATE_MIPMAP, (), target: GLenum];
-    glfn![glGetError, GL_GET_ERROR, GLenum];
-    glfn![glGetUniformLocation, GL_GET_UNIFORM_LOCATION, GLint, program: GLuint, name: *const GLchar];
-    glfn![glLinkProgram, GL_LINK_PROGRAM, (), program: GLuint];
-    glfn![glShaderSource, GL_SHADER_SOURCE, (), shader: GLuint, count: GLsizei, string: *const *const GLchar, length: *const GLint];
-    glfn![glTexImage2D, GL_TEX_IMAGE_2D, (), target: GLenum, level: GLint, internalformat: GLint, width: GLsizei, height: GLsizei, border: GLint, format: GLenum, typ: GLenum, data: *const c_void];
-    glfn![glTexParameteri, GL_TEX_PARAMETERI, (), target: GLenum, pname: GLenum, param: GLint];
-    glfn![glUniform1i, GL_UNIFORM1I, (), location: GLint, v0: GLint];
-    glfn![glUniform4f, GL_UNIFORM4F, (), location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat];
-    glfn![glUniformMatrix4fv, GL_UNIFORM_MATRIX4FV, (), location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat];
-    glfn![glUseProgram, GL_USE_PROGRAM, (), program: GLuint];
-    glfn![glVertexAttribPointer, GL_VERTEX_ATTRIB_POINTER, (), index: GLuint, size: GLint, typ: GLenum, normalized: GLboolean, stride: GLsizei, pointer: *const c_void];
-    glfn![glViewport, GL_VIEWPORT, (), x: GLint, y: GLint, width: GLsizei, height: GLsizei];
+    macro_rules! glfns {
+        ($(glfn![$name:ident, $once:ident, $ret:ty $(, $pname:ident: $ptype:ty)*];)*) => {
+            $(glfn![$name, $once, $ret $(, $pname: $ptype)*];)*
+
+            /// Resolves the entry points that have not been loaded yet
+            /// using `loader`. It returns the names of the unavailable
+            /// functions.
+            pub fn load(loader: &mut dyn FnMut(&str) -> Option<crate::glfw::GlProc>) -> Vec<&'static str> {
+                let mut missing = Vec::new();
+                $(
+                    if $once.get().is_none() {
+                        match loader(stringify!($name)) {
+                            Some(proc) => {
+                                let f = unsafe {
+                                    std::mem::transmute::<crate::glfw::GlProc, fn($($ptype),*) -> $ret>(proc)
+                                };
+                                let _ = $once.set(f);
+                            }
+                            None => missing.push(stringify!($name)),
+                        }
+                    }
+                )*
+                missing
+            }
+        };
+    }
+
+    glfns! {
+        glfn![glActiveTexture, GL_ACTIVE_TEXTURE, (), texture: GLenum];
+        glfn![glAttachShader, GL_ATTACH_SHADER, (), program: GLuint, shader: GLuint];
+        glfn![glBindBuffer, GL_BIND_BUFFER, (), target: GLenum, buffer: GLuint];
+        glfn![glBindFramebuffer, GL_BIND_FRAMEBUFFER, (), target: GLenum, framebuffer: GLuint];
+        glfn![glBindImageTexture, GL_BIND_IMAGE_TEXTURE, (), unit: GLuint, texture: GLuint, level: GLint, layered: GLboolean, layer: GLint, access: GLenum, format: GLenum];
+        glfn![glBindRenderbuffer, GL_BIND_RENDERBUFFER, (), target: GLenum, renderbuffer: GLuint];
+        glfn![glBindTexture, GL_BIND_TEXTURE, (), target: GLenum, texture: GLuint];
+        glfn![glBindTextureUnit, GL_BIND_TEXTURE_UNIT, (), unit: GLuint, texture: GLuint];
+        glfn![glBindVertexArray, GL_BIND_VERTEX_ARRAY, (), array: GLuint];
+        glfn![glBlendColor, GL_BLEND_COLOR, (), red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat];
+        glfn![glBlendEquation, GL_BLEND_EQUATION, (), mode: GLenum];
+        glfn![glBlendFunc, GL_BLEND_FUNC, (), sfactor: GLenum, dfactor: GLenum];
+        glfn![glBlendFuncSeparate, GL_BLEND_FUNC_SEPARATE, (), src_rgb: GLenum, dst_rgb: GLenum, src_alpha: GLenum, dst_alpha: GLenum];
+        glfn![glBlitFramebuffer, GL_BLIT_FRAMEBUFFER, (), src_x0: GLint, src_y0: GLint, src_x1: GLint, src_y1: GLint, dst_x0: GLint, dst_y0: GLint, dst_x1: GLint, dst_y1: GLint, mask: GLbitfield, filter: GLenum];
+        glfn![glBufferData, GL_BUFFER_DATA, (), target: GLenum, size: GLsizeiptr, data: *const c_void, usage: GLenum];
+        glfn![glBufferStorage, GL_BUFFER_STORAGE, (), target: GLenum, size: GLsizeiptr, data: *const c_void, flags: GLbitfield];
+        glfn![glBufferSubData, GL_BUFFER_SUB_DATA, (), target: GLenum, offset: GLintptr, size: GLsizeiptr, data: *const c_void];
+        glfn![glCheckFramebufferStatus, GL_CHECK_FRAMEBUFFER_STATUS, GLenum, target: GLenum];
+        glfn![glCheckNamedFramebufferStatus, GL_CHECK_NAMED_FRAMEBUFFER_STATUS, GLenum, framebuffer: GLuint, target: GLenum];
+        glfn![glClear, GL_CLEAR, (), mask: GLbitfield];
+        glfn![glClearColor, GL_CLEAR_COLOR, (), red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat];
+        glfn![glClearDepth, GL_CLEAR_DEPTH, (), depth: GLdouble];
+        glfn![glClearStencil, GL_CLEAR_STENCIL, (), s: GLint];
+        glfn![glClientWaitSync, GL_CLIENT_WAIT_SYNC, GLenum, sync: GLsync, flags: GLbitfield, timeout: GLuint64];
+        glfn![glCompileShader, GL_COMPILE_SHADER, (), shader: GLuint];
+        glfn![glCreateBuffers, GL_CREATE_BUFFERS, (), n: GLsizei, buffers: *mut GLuint];
+        glfn![glCreateFramebuffers, GL_CREATE_FRAMEBUFFERS, (), n: GLsizei, framebuffers: *mut GLuint];
+        glfn![glCreateProgram, GL_CREATE_PROGRAM, GLuint];
+        glfn![glCreateRenderbuffers, GL_CREATE_RENDERBUFFERS, (), n: GLsizei, renderbuffers: *mut GLuint];
+        glfn![glCreateShader, GL_CREATE_SHADER, GLuint, typ: GLenum];
+        glfn![glCreateTextures, GL_CREATE_TEXTURES, (), target: GLenum, n: GLsizei, textures: *mut GLuint];
+        glfn![glCreateVertexArrays, GL_CREATE_VERTEX_ARRAYS, (), n: GLsizei, arrays: *mut GLuint];
+        glfn![glDebugMessageCallback, GL_DEBUG_MESSAGE_CALLBACK, (), callback: *const c_void, user_param: *const c_void];
+        glfn![glDeleteBuffers, GL_DELETE_BUFFERS, (), n: GLsizei, buffers: *const GLuint];
+        glfn![glDeleteFramebuffers, GL_DELETE_FRAMEBUFFERS, (), n: GLsizei, framebuffers: *const GLuint];
+        glfn![glDeleteProgram, GL_DELETE_PROGRAM, (), program: GLuint];
+        glfn![glDeleteRenderbuffers, GL_DELETE_RENDERBUFFERS, (), n: GLsizei, renderbuffers: *const GLuint];
+        glfn![glDeleteShader, GL_DELETE_SHADER, (), shader: GLuint];
+        glfn![glDeleteSync, GL_DELETE_SYNC, (), sync: GLsync];
+        glfn![glDeleteTextures, GL_DELETE_TEXTURES, (), n: GLsizei, textures: *const GLuint];
+        glfn![glDeleteVertexArrays, GL_DELETE_VERTEX_ARRAYS, (), n: GLsizei, arrays: *const GLuint];
+        glfn![glDepthFunc, GL_DEPTH_FUNC, (), func: GLenum];
+        glfn![glDepthMask, GL_DEPTH_MASK, (), flag: GLboolean];
+        glfn![glDisable, GL_DISABLE, (), cap: GLenum];
+        glfn![glDispatchCompute, GL_DISPATCH_COMPUTE, (), num_groups_x: GLuint, num_groups_y: GLuint, num_groups_z: GLuint];
+        glfn![glDrawArrays, GL_DRAW_ARRAYS, (), mode: GLenum, first: GLint, count: GLsizei];
+        glfn![glDrawBuffers, GL_DRAW_BUFFERS, (), n: GLsizei, bufs: *const GLenum];
+        glfn![glDrawElements, GL_DRAW_ELEMENTS, (), mode: GLenum, count: GLsizei, typ: GLenum, indices: *const c_void];
+        glfn![glEnable, GL_ENABLE, (), cap: GLenum];
+        glfn![glEnableVertexArrayAttrib, GL_ENABLE_VERTEX_ARRAY_ATTRIB, (), vaobj: GLuint, index: GLuint];
+        glfn![glEnableVertexAttribArray, GL_ENABLE_VERTEX_ATTRIB_ARRAY, (), index: GLuint];
+        glfn![glFenceSync, GL_FENCE_SYNC, GLsync, condition: GLenum, flags: GLbitfield];
+        glfn![glFramebufferRenderbuffer, GL_FRAMEBUFFER_RENDERBUFFER, (), target: GLenum, attachment: GLenum, renderbuffertarget: GLenum, renderbuffer: GLuint];
+        glfn![glFramebufferTexture2D, GL_FRAMEBUFFER_TEXTURE_2D, (), target: GLenum, attachment: GLenum, textarget: GLenum, texture: GLuint, level: GLint];
+        glfn![glGenBuffers, GL_GEN_BUFFERS, (), n: GLsizei, buffers: *mut GLuint];
+        glfn![glGenFramebuffers, GL_GEN_FRAMEBUFFERS, (), n: GLsizei, framebuffers: *mut GLuint];
+        glfn![glGenRenderbuffers, GL_GEN_RENDERBUFFERS, (), n: GLsizei, renderbuffers: *mut GLuint];
+        glfn![glGenTextures, GL_GEN_TEXTURES, (), n: GLsizei, textures: *mut GLuint];
+        glfn![glGenVertexArrays, GL_GEN_VERTEX_ARRAYS, (), n: GLsizei, arrays: *mut GLuint];
+        glfn![glGenerateMipmap, GL_GENERATE_MIPMAP, (), target: GLenum];
+        glfn![glGenerateTextureMipmap, GL_GENERATE_TEXTURE_MIPMAP, (), texture: GLuint];
+        glfn![glGetError, GL_GET_ERROR, GLenum];
+        glfn![glGetFloatv, GL_GET_FLOATV, (), pname: GLenum, data: *mut GLfloat];
+        glfn![glGetIntegeri_v, GL_GET_INTEGERI_V, (), target: GLenum, index: GLuint, data: *mut GLint];
+        glfn![glGetIntegerv, GL_GET_INTEGERV, (), pname: GLenum, data: *mut GLint];
+        glfn![glGetProgramInfoLog, GL_GET_PROGRAM_INFO_LOG, (), program: GLuint, buf_size: GLsizei, length: *mut GLsizei, info_log: *mut GLchar];
+        glfn![glGetProgramiv, GL_GET_PROGRAMIV, (), program: GLuint, pname: GLenum, params: *mut GLint];
+        glfn![glGetShaderInfoLog, GL_GET_SHADER_INFO_LOG, (), shader: GLuint, buf_size: GLsizei, length: *mut GLsizei, info_log: *mut GLchar];
+        glfn![glGetShaderiv, GL_GET_SHADERIV, (), shader: GLuint, pname: GLenum, params: *mut GLint];
+        glfn![glGetString, GL_GET_STRING, *const GLubyte, name: GLenum];
+        glfn![glGetStringi, GL_GET_STRINGI, *const GLubyte, name: GLenum, index: GLuint];
+        glfn![glGetUniformLocation, GL_GET_UNIFORM_LOCATION, GLint, program: GLuint, name: *const GLchar];
+        glfn![glLineWidth, GL_LINE_WIDTH, (), width: GLfloat];
+        glfn![glLinkProgram, GL_LINK_PROGRAM, (), program: GLuint];
+        glfn![glMapBuffer, GL_MAP_BUFFER, *mut c_void, target: GLenum, access: GLenum];
+        glfn![glMapBufferRange, GL_MAP_BUFFER_RANGE, *mut c_void, target: GLenum, offset: GLintptr, length: GLsizeiptr, access: GLbitfield];
+        glfn![glMemoryBarrier, GL_MEMORY_BARRIER, (), barriers: GLbitfield];
+        glfn![glNamedBufferData, GL_NAMED_BUFFER_DATA, (), buffer: GLuint, size: GLsizeiptr, data: *const c_void, usage: GLenum];
+        glfn![glNamedBufferStorage, GL_NAMED_BUFFER_STORAGE, (), buffer: GLuint, size: GLsizeiptr, data: *const c_void, flags: GLbitfield];
+        glfn![glNamedBufferSubData, GL_NAMED_BUFFER_SUB_DATA, (), buffer: GLuint, offset: GLintptr, size: GLsizeiptr, data: *const c_void];
+        glfn![glNamedFramebufferRenderbuffer, GL_NAMED_FRAMEBUFFER_RENDERBUFFER, (), framebuffer: GLuint, attachment: GLenum, renderbuffertarget: GLenum, renderbuffer: GLuint];
+        glfn![glNamedFramebufferTexture, GL_NAMED_FRAMEBUFFER_TEXTURE, (), framebuffer: GLuint, attachment: GLenum, texture: GLuint, level: GLint];
+        glfn![glNamedRenderbufferStorage, GL_NAMED_RENDERBUFFER_STORAGE, (), renderbuffer: GLuint, internalformat: GLenum, width: GLsizei, height: GLsizei];
+        glfn![glPixelStorei, GL_PIXEL_STOREI, (), pname: GLenum, param: GLint];
+        glfn![glPointSize, GL_POINT_SIZE, (), size: GLfloat];
+        glfn![glPolygonOffset, GL_POLYGON_OFFSET, (), factor: GLfloat, units: GLfloat];
+        glfn![glReadBuffer, GL_READ_BUFFER, (), src: GLenum];
+        glfn![glRenderbufferStorage, GL_RENDERBUFFER_STORAGE, (), target: GLenum, internalformat: GLenum, width: GLsizei, height: GLsizei];
+        glfn![glRenderbufferStorageMultisample, GL_RENDERBUFFER_STORAGE_MULTISAMPLE, (), target: GLenum, samples: GLsizei, internalformat: GLenum, width: GLsizei, height: GLsizei];
+        glfn![glScissor, GL_SCISSOR, (), x: GLint, y: GLint, width: GLsizei, height: GLsizei];
+        glfn![glScissorIndexed, GL_SCISSOR_INDEXED, (), index: GLuint, left: GLint, bottom: GLint, width: GLsizei, height: GLsizei];
+        glfn![glShaderSource, GL_SHADER_SOURCE, (), shader: GLuint, count: GLsizei, string: *const *const GLchar, length: *const GLint];
+        glfn![glStencilFunc, GL_STENCIL_FUNC, (), func: GLenum, reference: GLint, mask: GLuint];
+        glfn![glStencilMask, GL_STENCIL_MASK, (), mask: GLuint];
+        glfn![glStencilOp, GL_STENCIL_OP, (), sfail: GLenum, dpfail: GLenum, dppass: GLenum];
+        glfn![glTexImage2D, GL_TEX_IMAGE_2D, (), target: GLenum, level: GLint, internalformat: GLint, width: GLsizei, height: GLsizei, border: GLint, format: GLenum, typ: GLenum, data: *const c_void];
+        glfn![glTexImage2DMultisample, GL_TEX_IMAGE_2D_MULTISAMPLE, (), target: GLenum, samples: GLsizei, internalformat: GLenum, width: GLsizei, height: GLsizei, fixedsamplelocations: GLboolean];
+        glfn![glTexImage3D, GL_TEX_IMAGE_3D, (), target: GLenum, level: GLint, internalformat: GLint, width: GLsizei, height: GLsizei, depth: GLsizei, border: GLint, format: GLenum, typ: GLenum, data: *const c_void];
+        glfn![glTexParameteri, GL_TEX_PARAMETERI, (), target: GLenum, pname: GLenum, param: GLint];
+        glfn![glTexStorage2D, GL_TEX_STORAGE_2D, (), target: GLenum, levels: GLsizei, internalformat: GLenum, width: GLsizei, height: GLsizei];
+        glfn![glTexSubImage2D, GL_TEX_SUB_IMAGE_2D, (), target: GLenum, level: GLint, xoffset: GLint, yoffset: GLint, width: GLsizei, height: GLsizei, format: GLenum, typ: GLenum, pixels: *const c_void];
+        glfn![glTexSubImage3D, GL_TEX_SUB_IMAGE_3D, (), target: GLenum, level: GLint, xoffset: GLint, yoffset: GLint, zoffset: GLint, width: GLsizei, height: GLsizei, depth: GLsizei, format: GLenum, typ: GLenum, pixels: *const c_void];
+        glfn![glTextureParameteri, GL_TEXTURE_PARAMETERI, (), texture: GLuint, pname: GLenum, param: GLint];
+        glfn![glTextureStorage2D, GL_TEXTURE_STORAGE_2D, (), texture: GLuint, levels: GLsizei, internalformat: GLenum, width: GLsizei, height: GLsizei];
+        glfn![glTextureSubImage2D, GL_TEXTURE_SUB_IMAGE_2D, (), texture: GLuint, level: GLint, xoffset: GLint, yoffset: GLint, width: GLsizei, height: GLsizei, format: GLenum, typ: GLenum, pixels: *const c_void];
+        glfn![glUniform1f, GL_UNIFORM1F, (), location: GLint, v0: GLfloat];
+        glfn![glUniform1fv, GL_UNIFORM1FV, (), location: GLint, count: GLsizei, value: *const GLfloat];
+        glfn![glUniform1i, GL_UNIFORM1I, (), location: GLint, v0: GLint];
+        glfn![glUniform1iv, GL_UNIFORM1IV, (), location: GLint, count: GLsizei, value: *const GLint];
+        glfn![glUniform1ui, GL_UNIFORM1UI, (), location: GLint, v0: GLuint];
+        glfn![glUniform2f, GL_UNIFORM2F, (), location: GLint, v0: GLfloat, v1: GLfloat];
+        glfn![glUniform2fv, GL_UNIFORM2FV, (), location: GLint, count: GLsizei, value: *const GLfloat];
+        glfn![glUniform2i, GL_UNIFORM2I, (), location: GLint, v0: GLint, v1: GLint];
+        glfn![glUniform2ui, GL_UNIFORM2UI, (), location: GLint, v0: GLuint, v1: GLuint];
+        glfn![glUniform3f, GL_UNIFORM3F, (), location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat];
+        glfn![glUniform3fv, GL_UNIFORM3FV, (), location: GLint, count: GLsizei, value: *const GLfloat];
+        glfn![glUniform3i, GL_UNIFORM3I, (), location: GLint, v0: GLint, v1: GLint, v2: GLint];
+        glfn![glUniform3ui, GL_UNIFORM3UI, (), location: GLint, v0: GLuint, v1: GLuint, v2: GLuint];
+        glfn![glUniform4f, GL_UNIFORM4F, (), location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat, v3: GLfloat];
+        glfn![glUniform4fv, GL_UNIFORM4FV, (), location: GLint, count: GLsizei, value: *const GLfloat];
+        glfn![glUniform4i, GL_UNIFORM4I, (), location: GLint, v0: GLint, v1: GLint, v2: GLint, v3: GLint];
+        glfn![glUniform4ui, GL_UNIFORM4UI, (), location: GLint, v0: GLuint, v1: GLuint, v2: GLuint, v3: GLuint];
+        glfn![glUniformMatrix2fv, GL_UNIFORM_MATRIX2FV, (), location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat];
+        glfn![glUniformMatrix3fv, GL_UNIFORM_MATRIX3FV, (), location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat];
+        glfn![glUniformMatrix4fv, GL_UNIFORM_MATRIX4FV, (), location: GLint, count: GLsizei, transpose: GLboolean, value: *const GLfloat];
+        glfn![glUnmapBuffer, GL_UNMAP_BUFFER, GLboolean, target: GLenum];
+        glfn![glUseProgram, GL_USE_PROGRAM, (), program: GLuint];
+        glfn![glVertexArrayAttribBinding, GL_VERTEX_ARRAY_ATTRIB_BINDING, (), vaobj: GLuint, attribindex: GLuint, bindingindex: GLuint];
+        glfn![glVertexArrayAttribFormat, GL_VERTEX_ARRAY_ATTRIB_FORMAT, (), vaobj: GLuint, attribindex: GLuint, size: GLint, typ: GLenum, normalized: GLboolean, relativeoffset: GLuint];
+        glfn![glVertexArrayElementBuffer, GL_VERTEX_ARRAY_ELEMENT_BUFFER, (), vaobj: GLuint, buffer: GLuint];
+        glfn![glVertexArrayVertexBuffer, GL_VERTEX_ARRAY_VERTEX_BUFFER, (), vaobj: GLuint, bindingindex: GLuint, buffer: GLuint, offset: GLintptr, stride: GLsizei];
+        glfn![glVertexAttribPointer, GL_VERTEX_ATTRIB_POINTER, (), index: GLuint, size: GLint, typ: GLenum, normalized: GLboolean, stride: GLsizei, pointer: *const c_void];
+        glfn![glViewport, GL_VIEWPORT, (), x: GLint, y: GLint, width: GLsizei, height: GLsizei];
+        glfn![glViewportArrayv, GL_VIEWPORT_ARRAYV, (), first: GLuint, count: GLsizei, v: *const GLfloat];
+        glfn![glViewportIndexedf, GL_VIEWPORT_INDEXEDF, (), index: GLuint, x: GLfloat, y: GLfloat, w: GLfloat, h: GLfloat];
+    }
 }
 
+/// No error has been recorded.
+pub const NO_ERROR: u32 = 0;
+
+/// No buffer.
+pub const NONE: u32 = 0;
+
+/// Blend factor `(0, 0, 0, 0)`.
+pub const ZERO: u32 = 0;
+
+/// Blend factor `(1, 1, 1, 1)`.
+pub const ONE: u32 = 1;
+
+/// The mapping may be used to read buffer data.
+pub const MAP_READ_BIT: u32 = 0x0001;
+
+/// The mapping may be used to modify buffer data.
+pub const MAP_WRITE_BIT: u32 = 0x0002;
+
+/// The previous contents of the mapped range may be discarded.
+pub const MAP_INVALIDATE_RANGE_BIT: u32 = 0x0004;
+
+/// The previous contents of the entire buffer may be discarded.
+pub const MAP_INVALIDATE_BUFFER_BIT: u32 = 0x0008;
+
+/// Modified subranges of the mapping are flushed explicitly.
+pub const MAP_FLUSH_EXPLICIT_BIT: u32 = 0x0010;
+
+/// The GL does not synchronize pending operations on the buffer
+/// before mapping it.
+pub const MAP_UNSYNCHRONIZED_BIT: u32 = 0x0020;
+
+/// The buffer may remain mapped while it is used by the GL.
+pub const MAP_PERSISTENT_BIT: u32 = 0x0040;
+
+/// Writes through a persistent mapping are visible to the GL without
+/// explicit synchronization.
+pub const MAP_COHERENT_BIT: u32 = 0x0080;
+
+/// The contents of an immutable buffer may be updated with
+/// [`buffer_sub_data`].
+pub const DYNAMIC_STORAGE_BIT: u32 = 0x0100;
+
+/// The data store of an immutable buffer should be allocated in
+/// client memory.
+pub const CLIENT_STORAGE_BIT: u32 = 0x0200;
+
+/// Flushes the commands of the sync object's context when waiting.
+pub const SYNC_FLUSH_COMMANDS_BIT: u32 = 0x0001;
+
+/// Texture fetches after the barrier reflect the data written by
+/// shaders before it.
+pub const TEXTURE_FETCH_BARRIER_BIT: u32 = 0x0008;
+
+/// Image loads and stores after the barrier reflect the data written
+/// by shaders before it.
+pub const SHADER_IMAGE_ACCESS_BARRIER_BIT: u32 = 0x0020;
+
+/// Pixel transfers from and to textures after the barrier reflect the
+/// data written by shaders before it.
+pub const TEXTURE_UPDATE_BARRIER_BIT: u32 = 0x0100;
+
+/// All the memory barriers.
+pub const ALL_BARRIER_BITS: u32 = 0xffffffff;
+
+/// Indicates the depth buffer.
+pub const DEPTH_BUFFER_BIT: u32 = 0x00000100;
+
+/// Indicates the stencil buffer.
+pub const STENCIL_BUFFER_BIT: u32 = 0x00000400;
+
 /// Indicates the buffers currently enabled for color writing.
 pub const COLOR_BUFFER_BIT: u32 = 0x00004000;
 
+/// Points primitive.
+pub const POINTS: u32 = 0x0000;
+
+/// Lines primitive.
+pub const LINES: u32 = 0x0001;
+
+/// Line loop primitive.
+pub const LINE_LOOP: u32 = 0x0002;
+
+/// Line strip primitive.
+pub const LINE_STRIP: u32 = 0x0003;
+
 /// Triangles primitive.
 pub const TRIANGLES: u32 = 0x0004;
 
+/// Triangle strip primitive.
+pub const TRIANGLE_STRIP: u32 = 0x0005;
+
+/// Triangle fan primitive.
+pub const TRIANGLE_FAN: u32 = 0x0006;
+
+/// Never passes.
+pub const NEVER: u32 = 0x0200;
+
+/// Passes if the incoming value is less than the stored value.
+pub const LESS: u32 = 0x0201;
+
+/// Passes if the incoming value is equal to the stored value.
+pub const EQUAL: u32 = 0x0202;
+
+/// Passes if the incoming value is less than or equal to the stored
+/// value.
+pub const LEQUAL: u32 = 0x0203;
+
+/// Passes if the incoming value is greater than the stored value.
+pub const GREATER: u32 = 0x0204;
+
+/// Passes if the incoming value is not equal to the stored value.
+pub const NOTEQUAL: u32 = 0x0205;
+
+/// Passes if the incoming value is greater than or equal to the
+/// stored value.
+pub const GEQUAL: u32 = 0x0206;
+
+/// Always passes.
+pub const ALWAYS: u32 = 0x0207;
+
+/// Blend factor `(Rs, Gs, Bs, As)`.
+pub const SRC_COLOR: u32 = 0x0300;
+
+/// Blend factor `(1 - Rs, 1 - Gs, 1 - Bs, 1 - As)`.
+pub const ONE_MINUS_SRC_COLOR: u32 = 0x0301;
+
+/// Blend factor `(As, As, As, As)`.
+pub const SRC_ALPHA: u32 = 0x0302;
+
+/// Blend factor `(1 - As, 1 - As, 1 - As, 1 - As)`.
+pub const ONE_MINUS_SRC_ALPHA: u32 = 0x0303;
+
+/// Blend factor `(Ad, Ad, Ad, Ad)`.
+pub const DST_ALPHA: u32 = 0x0304;
+
+/// Blend factor `(1 - Ad, 1 - Ad, 1 - Ad, 1 - Ad)`.
+pub const ONE_MINUS_DST_ALPHA: u32 = 0x0305;
+
+/// Blend factor `(Rd, Gd, Bd, Ad)`.
+pub const DST_COLOR: u32 = 0x0306;
+
+/// Blend factor `(1 - Rd, 1 - Gd, 1 - Bd, 1 - Ad)`.
+pub const ONE_MINUS_DST_COLOR: u32 = 0x0307;
+
+/// Blend factor `(i, i, i, 1)` with `i = min(As, 1 - Ad)`.
+pub const SRC_ALPHA_SATURATE: u32 = 0x0308;
+
+/// An unacceptable value is specified for an enumerated argument.
+pub const INVALID_ENUM: u32 = 0x0500;
+
+/// A numeric argument is out of range.
+pub const INVALID_VALUE: u32 = 0x0501;
+
+/// The specified operation is not allowed in the current state.
+pub const INVALID_OPERATION: u32 = 0x0502;
+
+/// There is not enough memory left to execute the command.
+pub const OUT_OF_MEMORY: u32 = 0x0505;
+
+/// The framebuffer object is not complete.
+pub const INVALID_FRAMEBUFFER_OPERATION: u32 = 0x0506;
+
+/// If enabled, do depth comparisons and update the depth buffer.
+pub const DEPTH_TEST: u32 = 0x0b71;
+
+/// If enabled, do stencil testing and update the stencil buffer.
+pub const STENCIL_TEST: u32 = 0x0b90;
+
+/// If enabled, blend the computed fragment color values with the
+/// values in the color buffers.
+pub const BLEND: u32 = 0x0be2;
+
+/// If enabled, discard fragments that are outside the scissor box.
+pub const SCISSOR_TEST: u32 = 0x0c11;
+
+/// Alignment requirements for the start of each pixel row in memory
+/// when unpacking pixel data.
+pub const UNPACK_ALIGNMENT: u32 = 0x0cf5;
+
+/// Maximum number of clip distances.
+pub const MAX_CLIP_DISTANCES: u32 = 0x0d32;
+
+/// Maximum width and height of a texture.
+pub const MAX_TEXTURE_SIZE: u32 = 0x0d33;
+
+/// Maximum width and height of the viewport.
+pub const MAX_VIEWPORT_DIMS: u32 = 0x0d3a;
+
 /// 2D texture.
 pub const TEXTURE_2D: u32 = 0x0de1;
 
-/// Unsigned integer data type.
-pub const UNSIGNED_INT: u32 = 0x1405;
+/// Byte data type.
+pub const BYTE: u32 = 0x1400;
 
 /// Unsigned byte data type.
 pub const UNSIGNED_BYTE: u32 = 0x1401;
 
+/// Short data type.
+pub const SHORT: u32 = 0x1402;
+
+/// Unsigned short data type.
+pub const UNSIGNED_SHORT: u32 = 0x1403;
+
+/// Integer data type.
+pub const INT: u32 = 0x1404;
+
+/// Unsigned integer data type.
+pub const UNSIGNED_INT: u32 = 0x1405;
+
 /// Float data type.
 pub const FLOAT: u32 = 0x1406;
 
+/// Double data type.
+pub const DOUBLE: u32 = 0x140a;
+
+/// Half-precision float data type.
+pub const HALF_FLOAT: u32 = 0x140b;
+
+/// Bitwise inverts the stencil value.
+pub const INVERT: u32 = 0x150a;
+
+/// Single-component format.
+pub const RED: u32 = 0x1903;
+
 /// RGB format.
 pub const RGB: u32 = 0x1907;
 
 /// RGBA format.
 pub const RGBA: u32 = 0x1908;
 
+/// Keeps the current stencil value.
+pub const KEEP: u32 = 0x1e00;
+
+/// Sets the stencil value to the reference value.
+pub const REPLACE: u32 = 0x1e01;
+
+/// Increments the stencil value, clamping to the maximum value.
+pub const INCR: u32 = 0x1e02;
+
+/// Decrements the stencil value, clamping to zero.
+pub const DECR: u32 = 0x1e03;
+
+/// Company responsible for the GL implementation.
+pub const VENDOR: u32 = 0x1f00;
+
+/// Name of the renderer.
+pub const RENDERER: u32 = 0x1f01;
+
+/// Version or release number.
+pub const VERSION: u32 = 0x1f02;
+
+/// Supported extensions.
+pub const EXTENSIONS: u32 = 0x1f03;
+
+/// Nearest neighbor filtering.
+pub const NEAREST: i32 = 0x2600;
+
 /// Linear filtering.
 pub const LINEAR: i32 = 0x2601;
 
@@ -122,26 +502,345 @@ pub const TEXTURE_WRAP_T: u32 = 0x2803;
 /// Repeats the texture image.
 pub const REPEAT: i32 = 0x2901;
 
+/// If enabled, the polygon offset is applied to polygons rasterized
+/// as points.
+pub const POLYGON_OFFSET_POINT: u32 = 0x2a01;
+
+/// If enabled, the polygon offset is applied to polygons rasterized
+/// as lines.
+pub const POLYGON_OFFSET_LINE: u32 = 0x2a02;
+
+/// If enabled, primitives are clipped against the half-space defined
+/// by the `gl_ClipDistance[0]` output of the last vertex processing
+/// stage. Vertices with negative distance are clipped. For instance,
+/// a planar reflection pass can clip the geometry below a water plane
+/// by writing `gl_ClipDistance[0] = dot(worldPos, plane)`, where
+/// `plane` is a `vec4` with the plane normal and distance, and
+/// `worldPos` has `w = 1`. The `CLIP_DISTANCE1` to `CLIP_DISTANCE7`
+/// capabilities enable the remaining elements of the array.
+pub const CLIP_DISTANCE0: u32 = 0x3000;
+
+/// If enabled, primitives are clipped against `gl_ClipDistance[1]`.
+pub const CLIP_DISTANCE1: u32 = 0x3001;
+
+/// If enabled, primitives are clipped against `gl_ClipDistance[2]`.
+pub const CLIP_DISTANCE2: u32 = 0x3002;
+
+/// If enabled, primitives are clipped against `gl_ClipDistance[3]`.
+pub const CLIP_DISTANCE3: u32 = 0x3003;
+
+/// If enabled, primitives are clipped against `gl_ClipDistance[4]`.
+pub const CLIP_DISTANCE4: u32 = 0x3004;
+
+/// If enabled, primitives are clipped against `gl_ClipDistance[5]`.
+pub const CLIP_DISTANCE5: u32 = 0x3005;
+
+/// If enabled, primitives are clipped against `gl_ClipDistance[6]`.
+pub const CLIP_DISTANCE6: u32 = 0x3006;
+
+/// If enabled, primitives are clipped against `gl_ClipDistance[7]`.
+pub const CLIP_DISTANCE7: u32 = 0x3007;
+
+/// Blend factor `(Rc, Gc, Bc, Ac)`.
+pub const CONSTANT_COLOR: u32 = 0x8001;
+
+/// Blend factor `(1 - Rc, 1 - Gc, 1 - Bc, 1 - Ac)`.
+pub const ONE_MINUS_CONSTANT_COLOR: u32 = 0x8002;
+
+/// Blend factor `(Ac, Ac, Ac, Ac)`.
+pub const CONSTANT_ALPHA: u32 = 0x8003;
+
+/// Blend factor `(1 - Ac, 1 - Ac, 1 - Ac, 1 - Ac)`.
+pub const ONE_MINUS_CONSTANT_ALPHA: u32 = 0x8004;
+
+/// Adds source and destination.
+pub const FUNC_ADD: u32 = 0x8006;
+
+/// Takes the minimum of source and destination.
+pub const MIN: u32 = 0x8007;
+
+/// Takes the maximum of source and destination.
+pub const MAX: u32 = 0x8008;
+
+/// Subtracts destination from source.
+pub const FUNC_SUBTRACT: u32 = 0x800a;
+
+/// Subtracts source from destination.
+pub const FUNC_REVERSE_SUBTRACT: u32 = 0x800b;
+
+/// If enabled, the polygon offset is applied to filled polygons.
+pub const POLYGON_OFFSET_FILL: u32 = 0x8037;
+
+/// RGB format with 8-bit components.
+pub const RGB8: u32 = 0x8051;
+
+/// RGBA format with 8-bit components.
+pub const RGBA8: u32 = 0x8058;
+
+/// 3D texture.
+pub const TEXTURE_3D: u32 = 0x806f;
+
+/// Texture wrapping mode of the r coordinate.
+pub const TEXTURE_WRAP_R: u32 = 0x8072;
+
+/// If enabled, use multiple fragment samples in computing the final
+/// color of a pixel.
+pub const MULTISAMPLE: u32 = 0x809d;
+
+/// Clamps texture coordinates to the edge of the texture.
+pub const CLAMP_TO_EDGE: i32 = 0x812f;
+
+/// 16-bit depth component format.
+pub const DEPTH_COMPONENT16: u32 = 0x81a5;
+
+/// 24-bit depth component format.
+pub const DEPTH_COMPONENT24: u32 = 0x81a6;
+
+/// Depth and stencil attachment.
+pub const DEPTH_STENCIL_ATTACHMENT: u32 = 0x821a;
+
+/// Number of extensions supported by the GL.
+pub const NUM_EXTENSIONS: u32 = 0x821d;
+
+/// Two-component format.
+pub const RG: u32 = 0x8227;
+
+/// Single-component format with 8-bit components.
+pub const R8: u32 = 0x8229;
+
+/// Two-component format with 8-bit components.
+pub const RG8: u32 = 0x822b;
+
+/// Single-component format with 16-bit floating-point components.
+pub const R16F: u32 = 0x822d;
+
+/// Single-component format with 32-bit floating-point components.
+pub const R32F: u32 = 0x822e;
+
+/// Two-component format with 16-bit floating-point components.
+pub const RG16F: u32 = 0x822f;
+
+/// Two-component format with 32-bit floating-point components.
+pub const RG32F: u32 = 0x8230;
+
+/// Single-component format with 32-bit signed integer components.
+pub const R32I: u32 = 0x8235;
+
+/// Single-component format with 32-bit unsigned integer components.
+pub const R32UI: u32 = 0x8236;
+
+/// If enabled, debug messages are produced synchronously, in the
+/// context of the GL function that generated them.
+pub const DEBUG_OUTPUT_SYNCHRONOUS: u32 = 0x8242;
+
+/// Maximum number of viewports.
+pub const MAX_VIEWPORTS: u32 = 0x825b;
+
 /// Texture unit 0.
 pub const TEXTURE0: u32 = 0x84c0;
 
+/// Increments the stencil value, wrapping to zero.
+pub const INCR_WRAP: u32 = 0x8507;
+
+/// Decrements the stencil value, wrapping to the maximum value.
+pub const DECR_WRAP: u32 = 0x8508;
+
+/// If enabled, the point size is taken from the `gl_PointSize`
+/// shader variable.
+pub const PROGRAM_POINT_SIZE: u32 = 0x8642;
+
+/// If enabled, the near and far clipping planes are disabled and the
+/// fragment depth is clamped to the depth range instead.
+pub const DEPTH_CLAMP: u32 = 0x864f;
+
+/// RGBA format with 32-bit floating-point components.
+pub const RGBA32F: u32 = 0x8814;
+
+/// RGB format with 32-bit floating-point components.
+pub const RGB32F: u32 = 0x8815;
+
+/// RGBA format with 16-bit floating-point components.
+pub const RGBA16F: u32 = 0x881a;
+
+/// RGB format with 16-bit floating-point components.
+pub const RGB16F: u32 = 0x881b;
+
+/// Maximum number of simultaneous outputs that may be written by a
+/// fragment shader.
+pub const MAX_DRAW_BUFFERS: u32 = 0x8824;
+
+/// Maximum number of generic vertex attributes.
+pub const MAX_VERTEX_ATTRIBS: u32 = 0x8869;
+
+/// Maximum number of texture image units accessible by the fragment
+/// shader.
+pub const MAX_TEXTURE_IMAGE_UNITS: u32 = 0x8872;
+
 /// Vertex data.
 pub const ARRAY_BUFFER: u32 = 0x8892;
 
 /// Indices used for indexed rendering.
 pub const ELEMENT_ARRAY_BUFFER: u32 = 0x8893;
 
+/// The mapping may only be used to read buffer data.
+pub const READ_ONLY: u32 = 0x88b8;
+
+/// The mapping may only be used to modify buffer data.
+pub const WRITE_ONLY: u32 = 0x88b9;
+
+/// The mapping may be used to read and modify buffer data.
+pub const READ_WRITE: u32 = 0x88ba;
+
+/// The data store contents are modified by the application, and used
+/// as the source for GL drawing and image specification commands. The
+/// data store contents will be modified once and used at most a few
+/// times.
+pub const STREAM_DRAW: u32 = 0x88e0;
+
 /// The data store contents are modified by the application, and used
 /// as the source for GL drawing and image specification commands. The
 /// data store contents will be modified once and used many times.
 pub const STATIC_DRAW: u32 = 0x88e4;
 
+/// The data store contents are modified by the application, and used
+/// as the source for GL drawing and image specification commands. The
+/// data store contents will be modified repeatedly and used many times.
+pub const DYNAMIC_DRAW: u32 = 0x88e8;
+
+/// Packed 24-bit depth and 8-bit stencil format.
+pub const DEPTH24_STENCIL8: u32 = 0x88f0;
+
+/// Maximum size in bytes of a uniform block.
+pub const MAX_UNIFORM_BLOCK_SIZE: u32 = 0x8a30;
+
 /// Fragment shader type.
 pub const FRAGMENT_SHADER: u32 = 0x8b30;
 
 /// Vertext shader type.
 pub const VERTEX_SHADER: u32 = 0x8b31;
 
+/// Whether the last compile operation on a shader was successful.
+pub const COMPILE_STATUS: u32 = 0x8b81;
+
+/// Whether the last link operation on a program was successful.
+pub const LINK_STATUS: u32 = 0x8b82;
+
+/// Length of the information log, including the null terminator.
+pub const INFO_LOG_LENGTH: u32 = 0x8b84;
+
+/// Version or release number of the shading language.
+pub const SHADING_LANGUAGE_VERSION: u32 = 0x8b8c;
+
+/// sRGB format with 8-bit components.
+pub const SRGB8: u32 = 0x8c41;
+
+/// sRGB format with 8-bit color components and linear 8-bit alpha.
+pub const SRGB8_ALPHA8: u32 = 0x8c43;
+
+/// Framebuffer target for read operations.
+pub const READ_FRAMEBUFFER: u32 = 0x8ca8;
+
+/// Framebuffer target for draw operations.
+pub const DRAW_FRAMEBUFFER: u32 = 0x8ca9;
+
+/// 32-bit floating-point depth component format.
+pub const DEPTH_COMPONENT32F: u32 = 0x8cac;
+
+/// Packed 32-bit floating-point depth and 8-bit stencil format.
+pub const DEPTH32F_STENCIL8: u32 = 0x8cad;
+
+/// The framebuffer is complete.
+pub const FRAMEBUFFER_COMPLETE: u32 = 0x8cd5;
+
+/// Maximum number of color attachments of a framebuffer.
+pub const MAX_COLOR_ATTACHMENTS: u32 = 0x8cdf;
+
+/// Color attachment 0.
+pub const COLOR_ATTACHMENT0: u32 = 0x8ce0;
+
+/// Color attachment 1.
+pub const COLOR_ATTACHMENT1: u32 = 0x8ce1;
+
+/// Color attachment 2.
+pub const COLOR_ATTACHMENT2: u32 = 0x8ce2;
+
+/// Color attachment 3.
+pub const COLOR_ATTACHMENT3: u32 = 0x8ce3;
+
+/// Color attachment 4.
+pub const COLOR_ATTACHMENT4: u32 = 0x8ce4;
+
+/// Color attachment 5.
+pub const COLOR_ATTACHMENT5: u32 = 0x8ce5;
+
+/// Color attachment 6.
+pub const COLOR_ATTACHMENT6: u32 = 0x8ce6;
+
+/// Color attachment 7.
+pub const COLOR_ATTACHMENT7: u32 = 0x8ce7;
+
+/// Depth attachment.
+pub const DEPTH_ATTACHMENT: u32 = 0x8d00;
+
+/// Stencil attachment.
+pub const STENCIL_ATTACHMENT: u32 = 0x8d20;
+
+/// Framebuffer target for both read and draw operations.
+pub const FRAMEBUFFER: u32 = 0x8d40;
+
+/// Renderbuffer target.
+pub const RENDERBUFFER: u32 = 0x8d41;
+
+/// 8-bit stencil index format.
+pub const STENCIL_INDEX8: u32 = 0x8d48;
+
+/// Maximum number of samples of multisample formats.
+pub const MAX_SAMPLES: u32 = 0x8d57;
+
+/// RGBA format with 32-bit unsigned integer components.
+pub const RGBA32UI: u32 = 0x8d70;
+
+/// RGBA format with 8-bit unsigned integer components.
+pub const RGBA8UI: u32 = 0x8d7c;
+
+/// RGBA format with 32-bit signed integer components.
+pub const RGBA32I: u32 = 0x8d82;
+
+/// RGBA format with 8-bit signed integer components.
+pub const RGBA8I: u32 = 0x8d8e;
+
+/// Geometry shader type.
+pub const GEOMETRY_SHADER: u32 = 0x8dd9;
+
+/// 2D multisample texture.
+pub const TEXTURE_2D_MULTISAMPLE: u32 = 0x9100;
+
+/// Sync condition signaled when all the preceding commands are
+/// complete.
+pub const SYNC_GPU_COMMANDS_COMPLETE: u32 = 0x9117;
+
+/// The sync object was already signaled.
+pub const ALREADY_SIGNALED: u32 = 0x911a;
+
+/// The sync object was not signaled before the timeout expired.
+pub const TIMEOUT_EXPIRED: u32 = 0x911b;
+
+/// The sync object was signaled before the timeout expired.
+pub const CONDITION_SATISFIED: u32 = 0x911c;
+
+/// An error occurred while waiting for the sync object.
+pub const WAIT_FAILED: u32 = 0x911d;
+
+/// Compute shader type.
+pub const COMPUTE_SHADER: u32 = 0x91b9;
+
+/// Maximum number of work groups that may be dispatched by a compute
+/// shader, indexed by dimension.
+pub const MAX_COMPUTE_WORK_GROUP_COUNT: u32 = 0x91be;
+
+/// Maximum size of a compute shader work group, indexed by dimension.
+pub const MAX_COMPUTE_WORK_GROUP_SIZE: u32 = 0x91bf;
+
 /// If enabled, debug messages are produced by a debug context.
 pub const DEBUG_OUTPUT: u32 = 0x92e0;
 
@@ -154,6 +853,64 @@ pub enum Error {
     /// Non-active uniform variable in program.
     NonActiveUniform(String),
 
+    /// All the available texture image units are in use.
+    TextureUnitsExhausted(u32),
+
+    /// The buffer data store could not be mapped.
+    MapBuffer,
+
+    /// The buffer data store was corrupted while it was mapped.
+    UnmapBuffer,
+
+    /// Waiting for a sync object failed.
+    WaitSync,
+
+    /// Shader compilation failed.
+    Compile {
+        /// Shader type.
+        typ: u32,
+
+        /// Information log of the shader.
+        log: String,
+    },
+
+    /// Program linking failed.
+    Link(String),
+
+    /// Invalid size of a [`PersistentBuffer`].
+    BufferSize {
+        /// Number of elements of every region.
+        region_len: usize,
+
+        /// Number of regions.
+        regions: usize,
+    },
+
+    /// Some OpenGL 3.3 core functions could not be loaded.
+    MissingFunctions(Vec<&'static str>),
+
+    /// The number of channels of an image does not match the pixel
+    /// format.
+    FormatMismatch {
+        /// Pixel format.
+        format: u32,
+
+        /// Number of channels of the image.
+        channels: usize,
+    },
+
+    /// The image is not a valid 3D LUT strip.
+    InvalidLut {
+        /// Width of the image.
+        width: usize,
+
+        /// Height of the image.
+        height: usize,
+
+        /// Number of channels of the image.
+        channels: usize,
+    },
+
     /// Invalid C string.
     InvalidCString(NulError),
 }
@@ -168,6 +925,44 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::NonActiveUniform(s) => write!(f, "non-active uniform variable in program: {s}"),
+            Error::TextureUnitsExhausted(max) => {
+                write!(f, "texture image units exhausted (max: {max})")
+            }
+            Error::MapBuffer => write!(f, "could not map buffer"),
+            Error::UnmapBuffer => write!(f, "buffer data store corrupted while mapped"),
+            Error::WaitSync => write!(f, "could not wait for sync object"),
+            Error::Compile { typ, log } => {
+                let typ = match *typ {
+                    VERTEX_SHADER => "vertex",
+                    FRAGMENT_SHADER => "fragment",
+                    GEOMETRY_SHADER => "geometry",
+                    COMPUTE_SHADER => "compute",
+                    _ => "unknown",
+                };
+                write!(f, "could not compile {typ} shader: {log}")
+            }
+            Error::Link(log) => write!(f, "could not link program: {log}"),
+            Error::BufferSize {
+                region_len,
+                regions,
+            } => write!(
+                f,
+                "invalid buffer size: {regions} regions of {region_len} elements"
+            ),
+            Error::MissingFunctions(names) => {
+                write!(f, "unavailable GL functions: {}", names.join(", "))
+            }
+            Error::FormatMismatch { format, channels } => {
+                write!(f, "format {format:#06x} does not match {channels} channels")
+            }
+            Error::InvalidLut {
+                width,
+                height,
+                channels,
+            } => write!(
+                f,
+                "invalid LUT strip: {width}x{height} image with {channels} channels"
+            ),
             Error::InvalidCString(err) => write!(f, "invalid C string: {err}"),
         }
     }
@@ -217,84 +1012,723 @@ impl Texture {
     pub fn zero() -> Texture {
         Texture(0)
     }
-}
-
-/// Uniform value.
-pub enum Uniform {
-    /// Integer uniform parameter.
-    Int(i32),
-
-    /// vec4 uniform parameter.
-    Vec4(Vec4<f32>),
-
-    /// mat4 uniform parameter.
-    Mat4(Mat4<f32>),
-}
 
-impl From<i32> for Uniform {
-    fn from(v: i32) -> Uniform {
-        Uniform::Int(v)
+    /// Returns the name of the texture object.
+    pub fn id(&self) -> u32 {
+        self.0
     }
 }
 
-impl From<Vec4<f32>> for Uniform {
-    fn from(v: Vec4<f32>) -> Uniform {
-        Uniform::Vec4(v)
+/// Renderbuffer object.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Renderbuffer(ffi::GLuint);
+
+impl Renderbuffer {
+    /// Returns the reserved renderbuffer object zero.
+    pub fn zero() -> Renderbuffer {
+        Renderbuffer(0)
     }
 }
 
-impl From<Mat4<f32>> for Uniform {
-    fn from(v: Mat4<f32>) -> Uniform {
-        Uniform::Mat4(v)
+/// Framebuffer object.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Framebuffer(ffi::GLuint);
+
+impl Framebuffer {
+    /// Returns the reserved framebuffer object zero, which is the
+    /// default framebuffer.
+    pub fn zero() -> Framebuffer {
+        Framebuffer(0)
     }
 }
 
-/// Uniform location.
+/// Sync object.
 #[derive(Clone, Copy)]
-pub struct UniformLocation(ffi::GLint);
-
-/// Texture parameter.
-pub enum TexParam {
-    /// Integer texture parameter for scalar commands.
-    Int(i32),
+pub struct SyncObject(ffi::GLsync);
+
+/// Texture unit allocator. It assigns consecutive texture units to
+/// the textures sampled by a program, so the `TEXTURE0 + n`
+/// arithmetic does not need to be maintained by hand.
+pub struct TextureUnits {
+    next: u32,
+    max: u32,
 }
 
-impl From<i32> for TexParam {
-    fn from(v: i32) -> TexParam {
-        TexParam::Int(v)
+impl Default for TextureUnits {
+    fn default() -> TextureUnits {
+        TextureUnits::new()
     }
 }
 
-define_enum! {
-    pub enum DebugSource(u32, "Debug source") {
-        Api            => (0x8246, "API"),
-        WindowSystem   => (0x8247, "Window system"),
-        ShaderCompiler => (0x8248, "Shader compiler"),
-        ThirdParty     => (0x8249, "Third party"),
-        Application    => (0x824a, "Application"),
-        Other          => (0x824b, "Other"),
+impl TextureUnits {
+    /// Creates a texture unit allocator limited by
+    /// [`MAX_TEXTURE_IMAGE_UNITS`].
+    pub fn new() -> TextureUnits {
+        TextureUnits {
+            next: 0,
+            max: get_integer(MAX_TEXTURE_IMAGE_UNITS) as u32,
+        }
     }
 
-    pub enum DebugType(u32, "Debug type") {
-        Error              => (0x824c, "Error"),
-        DeprecatedBehavior => (0x824d, "Deprecated behavior"),
-        UndefinedBehavior  => (0x824e, "Undefined behavior"),
-        Portability        => (0x824f, "Portability"),
-        Performance        => (0x8250, "Performance"),
-        Marker             => (0x8268, "Marker"),
-        PushGroup          => (0x8269, "Push group"),
-        PopGroup           => (0x826a, "Pop group"),
-        Other              => (0x8251, "Other"),
+    /// Assigns the next free texture unit to `texture`, binds the
+    /// texture to `target` and points the sampler uniform `name` to
+    /// the assigned unit. `program` must be the program currently in
+    /// use. It returns the index of the assigned texture unit.
+    pub fn bind(
+        &mut self,
+        program: Program,
+        name: &str,
+        target: u32,
+        texture: Texture,
+    ) -> Result<u32> {
+        if self.next >= self.max {
+            return Err(Error::TextureUnitsExhausted(self.max));
+        }
+        let location = get_uniform_location(program, name)?;
+        let unit = self.next;
+        active_texture(TEXTURE0 + unit);
+        bind_texture(target, texture);
+        uniform(location, (unit as i32).into());
+        self.next += 1;
+        Ok(unit)
     }
 
-    pub enum DebugSeverity(u32, "Debug severity") {
-        High         => (0x9146, "High"),
-        Medium       => (0x9147, "Medium"),
-        Low          => (0x9148, "Low"),
+    /// Releases all the assigned texture units.
+    pub fn reset(&mut self) {
+        self.next = 0;
+    }
+}
+
+/// Immutable buffer that stays persistently mapped. The buffer is
+/// split into regions that are written in turn, typically one per
+/// frame, so the application can stream data while the GL reads the
+/// regions written in previous frames. Fences prevent overwriting a
+/// region that is still in use.
+pub struct PersistentBuffer<T: Pod> {
+    buffer: Buffer,
+    ptr: *mut T,
+    region_len: usize,
+    fences: Vec<Option<SyncObject>>,
+    current: usize,
+}
+
+impl<T: Pod> PersistentBuffer<T> {
+    /// Creates a buffer with `regions` regions of `region_len`
+    /// elements each and maps it persistently. The buffer is left
+    /// bound to `target`. Both `region_len` and `regions` must be
+    /// greater than zero.
+    pub fn new(
+        target: u32,
+        region_len: usize,
+        regions: usize,
+    ) -> crate::Result<PersistentBuffer<T>> {
+        let size = mem::size_of::<T>()
+            .checked_mul(region_len)
+            .and_then(|size| size.checked_mul(regions))
+            .filter(|&size| size > 0 && size <= isize::MAX as usize)
+            .ok_or(Error::BufferSize {
+                region_len,
+                regions,
+            })
+            .context("creating persistent buffer")?;
+        let flags = MAP_WRITE_BIT | MAP_PERSISTENT_BIT | MAP_COHERENT_BIT;
+
+        let buffer = gen_buffers(1)[0];
+        bind_buffer(target, buffer);
+        buffer_storage::<T>(target, size, None, flags);
+        let ptr = map_buffer_range(target, 0, size, flags);
+        if ptr.is_null() {
+            delete_buffers(&[buffer]);
+            return Err(Error::MapBuffer).context("creating persistent buffer");
+        }
+
+        Ok(PersistentBuffer {
+            buffer,
+            ptr: ptr as *mut T,
+            region_len,
+            fences: vec![None; regions],
+            current: 0,
+        })
+    }
+
+    /// Returns the underlying buffer object.
+    pub fn buffer(&self) -> Buffer {
+        self.buffer
+    }
+
+    /// Returns the number of elements of every region.
+    pub fn region_len(&self) -> usize {
+        self.region_len
+    }
+
+    /// Returns the offset in elements of the current region. It can
+    /// be used as base vertex or base offset of the draw calls that
+    /// read the region.
+    pub fn offset(&self) -> usize {
+        self.current * self.region_len
+    }
+
+    /// Waits until the GL has finished reading the current region and
+    /// returns it.
+    pub fn region(&mut self) -> Result<&mut [T]> {
+        if let Some(fence) = self.fences[self.current].take() {
+            let status = loop {
+                let status = client_wait_sync(fence, SYNC_FLUSH_COMMANDS_BIT, 1_000_000_000);
+                if status != TIMEOUT_EXPIRED {
+                    break status;
+                }
+            };
+            delete_sync(fence);
+            if status == WAIT_FAILED {
+                return Err(Error::WaitSync);
+            }
+        }
+        let region = unsafe { self.ptr.add(self.offset()) };
+        Ok(unsafe { slice::from_raw_parts_mut(region, self.region_len) })
+    }
+
+    /// Inserts a fence after the commands that read the current
+    /// region and advances to the next one. It must be called after
+    /// issuing the draw calls of the frame.
+    pub fn advance(&mut self) {
+        if let Some(fence) = self.fences[self.current].replace(fence_sync()) {
+            delete_sync(fence);
+        }
+        self.current = (self.current + 1) % self.fences.len();
+    }
+
+    /// Unmaps and deletes the buffer. `target` is used to unmap the
+    /// buffer, which is bound to it.
+    pub fn delete(self, target: u32) {
+        for fence in self.fences.into_iter().flatten() {
+            delete_sync(fence);
+        }
+        bind_buffer(target, self.buffer);
+        unmap_buffer(target);
+        delete_buffers(&[self.buffer]);
+    }
+}
+
+/// Uniform location cache. It resolves the locations of the uniform
+/// variables of a program lazily and memoizes them, so they are not
+/// looked up by name every frame.
+pub struct UniformCache {
+    program: Program,
+    locations: HashMap<String, UniformLocation>,
+}
+
+impl UniformCache {
+    /// Creates an empty cache for `program`.
+    pub fn new(program: Program) -> UniformCache {
+        UniformCache {
+            program,
+            locations: HashMap::new(),
+        }
+    }
+
+    /// Returns the program whose locations are cached.
+    pub fn program(&self) -> Program {
+        self.program
+    }
+
+    /// Returns the location of the uniform variable `name`.
+    pub fn location(&mut self, name: &str) -> Result<UniformLocation> {
+        if let Some(location) = self.locations.get(name) {
+            return Ok(*location);
+        }
+        let location = get_uniform_location(self.program, name)?;
+        self.locations.insert(name.to_string(), location);
+        Ok(location)
+    }
+
+    /// Sets the value of the uniform variable `name`. The program
+    /// must be the program currently in use.
+    pub fn set<U: Into<Uniform>>(&mut self, name: &str, value: U) -> Result<()> {
+        let location = self.location(name)?;
+        uniform(location, value.into());
+        Ok(())
+    }
+
+    /// Forgets all the cached locations. It must be called after
+    /// relinking the program.
+    pub fn clear(&mut self) {
+        self.locations.clear();
+    }
+}
+
+/// Sets the source code of `shader` and compiles it. The
+/// information log is returned in case of error.
+fn compile(shader: Shader, typ: u32, src: &str) -> Result<()> {
+    shader_source(shader, &[src])?;
+    compile_shader(shader);
+    if get_shader(shader, COMPILE_STATUS) == 0 {
+        return Err(Error::Compile {
+            typ,
+            log: get_shader_info_log(shader),
+        });
+    }
+    Ok(())
+}
+
+/// Shader program built from source code. It owns a
+/// [`UniformCache`], so uniform variables can be set by name.
+pub struct ShaderProgram {
+    uniforms: UniformCache,
+}
+
+impl ShaderProgram {
+    /// Compiles the provided vertex, fragment and, optionally,
+    /// geometry shader sources and links them into a program. The
+    /// information log is returned in case of error, annotated with
+    /// the stage that failed.
+    pub fn from_sources(vs: &str, fs: &str, gs: Option<&str>) -> crate::Result<ShaderProgram> {
+        let mut stages = vec![(VERTEX_SHADER, vs), (FRAGMENT_SHADER, fs)];
+        if let Some(gs) = gs {
+            stages.push((GEOMETRY_SHADER, gs));
+        }
+
+        let mut shaders = Vec::new();
+        let compiled = stages.into_iter().try_for_each(|(typ, src)| {
+            let shader = create_shader(typ);
+            shaders.push(shader);
+            compile(shader, typ, src).with_context(|| match typ {
+                VERTEX_SHADER => "compiling vertex shader",
+                FRAGMENT_SHADER => "compiling fragment shader",
+                _ => "compiling geometry shader",
+            })
+        });
+        if let Err(err) = compiled {
+            shaders.into_iter().for_each(delete_shader);
+            return Err(err);
+        }
+
+        let program = create_program();
+        for shader in &shaders {
+            attach_shader(program, *shader);
+        }
+        link_program(program);
+        shaders.into_iter().for_each(delete_shader);
+        if get_program(program, LINK_STATUS) == 0 {
+            let log = get_program_info_log(program);
+            delete_program(program);
+            return Err(Error::Link(log)).context("linking program");
+        }
+
+        Ok(ShaderProgram {
+            uniforms: UniformCache::new(program),
+        })
+    }
+
+    /// Returns the underlying program object.
+    pub fn program(&self) -> Program {
+        self.uniforms.program()
+    }
+
+    /// Installs the program as part of the current rendering state.
+    pub fn bind(&self) {
+        use_program(self.program());
+    }
+
+    /// Sets the value of the uniform variable `name`. The program
+    /// must be bound.
+    pub fn set_uniform<U: Into<Uniform>>(&mut self, name: &str, value: U) -> Result<()> {
+        self.uniforms.set(name, value)
+    }
+
+    /// Returns the location of the uniform variable `name`.
+    pub fn uniform_location(&mut self, name: &str) -> Result<UniformLocation> {
+        self.uniforms.location(name)
+    }
+
+    /// Deletes the program.
+    pub fn delete(self) {
+        delete_program(self.program());
+    }
+}
+
+/// Ready-to-draw mesh. It owns a vertex array object, the vertex
+/// buffer and, optionally, an element buffer.
+pub struct Mesh {
+    vao: VertexArray,
+    vbo: Buffer,
+    ebo: Option<Buffer>,
+    count: usize,
+    mode: u32,
+}
+
+impl Mesh {
+    /// Creates a mesh from interleaved vertex data. `attribs`
+    /// describes the attributes of every vertex, whose size in bytes
+    /// is `stride`. If `stride` is 0, the vertices are considered
+    /// tightly packed and their size is computed from `attribs`. The
+    /// index of every attribute in the slice is used as its location.
+    /// If `indices` is provided, the mesh is drawn using them. The
+    /// primitive type defaults to [`TRIANGLES`].
+    pub fn new<T: Pod>(
+        vertices: &[T],
+        attribs: &[VertexAttrib],
+        stride: usize,
+        indices: Option<&[u32]>,
+    ) -> Mesh {
+        let stride = if stride == 0 {
+            attribs
+                .iter()
+                .map(|attrib| attrib.offset + attrib.size * type_size(attrib.typ))
+                .max()
+                .unwrap_or(0)
+        } else {
+            stride
+        };
+
+        let vao = gen_vertex_arrays(1)[0];
+        let vbo = gen_buffers(1)[0];
+
+        bind_vertex_array(vao);
+        bind_buffer(ARRAY_BUFFER, vbo);
+        buffer_data(ARRAY_BUFFER, vertices, STATIC_DRAW);
+
+        let ebo = indices.map(|indices| {
+            let ebo = gen_buffers(1)[0];
+            bind_buffer(ELEMENT_ARRAY_BUFFER, ebo);
+            buffer_data(ELEMENT_ARRAY_BUFFER, indices, STATIC_DRAW);
+            ebo
+        });
+
+        for (i, attrib) in attribs.iter().enumerate() {
+            vertex_attrib_pointer(
+                i as u32,
+                attrib.size,
+                attrib.typ,
+                attrib.normalized,
+                stride,
+                attrib.offset,
+            );
+            enable_vertex_attrib_array(i as u32);
+        }
+
+        bind_vertex_array(VertexArray::zero());
+        bind_buffer(ARRAY_BUFFER, Buffer::zero());
+
+        let count = match indices {
+            Some(indices) => indices.len(),
+            None => mem::size_of_val(vertices).checked_div(stride).unwrap_or(0),
+        };
+
+        Mesh {
+            vao,
+            vbo,
+            ebo,
+            count,
+            mode: TRIANGLES,
+        }
+    }
+
+    /// Creates a mesh from a slice of vertex structures. The layout
+    /// is described by [`Vertex::ATTRIBS`].
+    pub fn from_vertices<V: Vertex>(vertices: &[V], indices: Option<&[u32]>) -> Mesh {
+        Mesh::new(vertices, V::ATTRIBS, mem::size_of::<V>(), indices)
+    }
+
+    /// Sets the primitive type used to draw the mesh.
+    pub fn set_mode(&mut self, mode: u32) {
+        self.mode = mode;
+    }
+
+    /// Returns the vertex array object of the mesh.
+    pub fn vertex_array(&self) -> VertexArray {
+        self.vao
+    }
+
+    /// Binds the vertex array object of the mesh and renders it.
+    pub fn draw(&self) {
+        bind_vertex_array(self.vao);
+        match self.ebo {
+            Some(_) => draw_elements(self.mode, self.count, UNSIGNED_INT, 0),
+            None => draw_arrays(self.mode, 0, self.count as i32),
+        }
+    }
+
+    /// Deletes the vertex array object and the buffers of the mesh.
+    pub fn delete(self) {
+        delete_vertex_arrays(&[self.vao]);
+        delete_buffers(&[self.vbo]);
+        if let Some(ebo) = self.ebo {
+            delete_buffers(&[ebo]);
+        }
+    }
+}
+
+/// Returns the size in bytes of the specified data type.
+fn type_size(typ: u32) -> usize {
+    match typ {
+        BYTE | UNSIGNED_BYTE => 1,
+        SHORT | UNSIGNED_SHORT | HALF_FLOAT => 2,
+        INT | UNSIGNED_INT | FLOAT => 4,
+        DOUBLE => 8,
+        _ => 0,
+    }
+}
+
+/// Plain data types that can be uploaded to the GL as raw bytes.
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]` or `#[repr(transparent)]`, must
+/// not contain padding bytes and all their fields must be [`Pod`].
+pub unsafe trait Pod: Copy + 'static {}
+
+unsafe impl Pod for i8 {}
+unsafe impl Pod for u8 {}
+unsafe impl Pod for i16 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for f32 {}
+unsafe impl Pod for f64 {}
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+unsafe impl<T: Pod> Pod for Vec2<T> {}
+unsafe impl<T: Pod> Pod for Vec3<T> {}
+unsafe impl<T: Pod> Pod for Vec4<T> {}
+unsafe impl<T: Pod> Pod for Mat2<T> {}
+unsafe impl<T: Pod> Pod for Mat3<T> {}
+unsafe impl<T: Pod> Pod for Mat4<T> {}
+
+/// Describes a generic vertex attribute within a vertex structure.
+#[derive(Clone, Copy)]
+pub struct VertexAttrib {
+    /// Number of components.
+    pub size: usize,
+
+    /// Data type of each component.
+    pub typ: u32,
+
+    /// Whether fixed-point values are normalized.
+    pub normalized: bool,
+
+    /// Offset of the attribute within the vertex structure. It can be
+    /// computed with [`mem::offset_of`].
+    pub offset: usize,
+}
+
+/// Vertex structures whose layout can be described to a vertex array
+/// object.
+///
+/// ```
+/// use std::mem;
+///
+/// use hitchcock::gl::{self, Pod, Vertex, VertexAttrib};
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct MyVertex {
+///     pos: [f32; 3],
+///     uv: [f32; 2],
+/// }
+///
+/// unsafe impl Pod for MyVertex {}
+///
+/// impl Vertex for MyVertex {
+///     const ATTRIBS: &'static [VertexAttrib] = &[
+///         VertexAttrib {
+///             size: 3,
+///             typ: gl::FLOAT,
+///             normalized: false,
+///             offset: mem::offset_of!(MyVertex, pos),
+///         },
+///         VertexAttrib {
+///             size: 2,
+///             typ: gl::FLOAT,
+///             normalized: false,
+///             offset: mem::offset_of!(MyVertex, uv),
+///         },
+///     ];
+/// }
+/// ```
+pub trait Vertex: Pod {
+    /// Vertex attributes. The index of every attribute in the slice
+    /// is used as its location.
+    const ATTRIBS: &'static [VertexAttrib];
+}
+
+/// Uniform value. Matrices are stored in row-major order and
+/// transposed when uploaded.
+pub enum Uniform {
+    /// float uniform parameter.
+    Float(f32),
+
+    /// Integer uniform parameter.
+    Int(i32),
+
+    /// uint uniform parameter.
+    Uint(u32),
+
+    /// vec2 uniform parameter.
+    Vec2(Vec2<f32>),
+
+    /// vec3 uniform parameter.
+    Vec3(Vec3<f32>),
+
+    /// vec4 uniform parameter.
+    Vec4(Vec4<f32>),
+
+    /// ivec2 uniform parameter.
+    IVec2(Vec2<i32>),
+
+    /// ivec3 uniform parameter.
+    IVec3(Vec3<i32>),
+
+    /// ivec4 uniform parameter.
+    IVec4(Vec4<i32>),
+
+    /// uvec2 uniform parameter.
+    UVec2(Vec2<u32>),
+
+    /// uvec3 uniform parameter.
+    UVec3(Vec3<u32>),
+
+    /// uvec4 uniform parameter.
+    UVec4(Vec4<u32>),
+
+    /// mat2 uniform parameter.
+    Mat2(Mat2<f32>),
+
+    /// mat3 uniform parameter.
+    Mat3(Mat3<f32>),
+
+    /// mat4 uniform parameter.
+    Mat4(Mat4<f32>),
+
+    /// float array uniform parameter.
+    FloatArray(Vec<f32>),
+
+    /// Integer array uniform parameter.
+    IntArray(Vec<i32>),
+
+    /// vec2 array uniform parameter.
+    Vec2Array(Vec<Vec2<f32>>),
+
+    /// vec3 array uniform parameter.
+    Vec3Array(Vec<Vec3<f32>>),
+
+    /// vec4 array uniform parameter.
+    Vec4Array(Vec<Vec4<f32>>),
+
+    /// mat2 array uniform parameter.
+    Mat2Array(Vec<Mat2<f32>>),
+
+    /// mat3 array uniform parameter.
+    Mat3Array(Vec<Mat3<f32>>),
+
+    /// mat4 array uniform parameter.
+    Mat4Array(Vec<Mat4<f32>>),
+}
+
+macro_rules! impl_from_for_uniform {
+    ($($typ:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$typ> for Uniform {
+                fn from(v: $typ) -> Uniform {
+                    Uniform::$variant(v)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_uniform! {
+    f32 => Float,
+    i32 => Int,
+    u32 => Uint,
+    Vec2<f32> => Vec2,
+    Vec3<f32> => Vec3,
+    Vec4<f32> => Vec4,
+    Vec2<i32> => IVec2,
+    Vec3<i32> => IVec3,
+    Vec4<i32> => IVec4,
+    Vec2<u32> => UVec2,
+    Vec3<u32> => UVec3,
+    Vec4<u32> => UVec4,
+    Mat2<f32> => Mat2,
+    Mat3<f32> => Mat3,
+    Mat4<f32> => Mat4,
+    Vec<f32> => FloatArray,
+    Vec<i32> => IntArray,
+    Vec<Vec2<f32>> => Vec2Array,
+    Vec<Vec3<f32>> => Vec3Array,
+    Vec<Vec4<f32>> => Vec4Array,
+    Vec<Mat2<f32>> => Mat2Array,
+    Vec<Mat3<f32>> => Mat3Array,
+    Vec<Mat4<f32>> => Mat4Array,
+}
+
+/// Uniform location.
+#[derive(Clone, Copy)]
+pub struct UniformLocation(ffi::GLint);
+
+/// Texture parameter.
+pub enum TexParam {
+    /// Integer texture parameter for scalar commands.
+    Int(i32),
+}
+
+impl From<i32> for TexParam {
+    fn from(v: i32) -> TexParam {
+        TexParam::Int(v)
+    }
+}
+
+define_enum! {
+    pub enum DebugSource(u32, "Debug source") {
+        Api            => (0x8246, "API"),
+        WindowSystem   => (0x8247, "Window system"),
+        ShaderCompiler => (0x8248, "Shader compiler"),
+        ThirdParty     => (0x8249, "Third party"),
+        Application    => (0x824a, "Application"),
+        Other          => (0x824b, "Other"),
+    }
+
+    pub enum DebugType(u32, "Debug type") {
+        Error              => (0x824c, "Error"),
+        DeprecatedBehavior => (0x824d, "Deprecated behavior"),
+        UndefinedBehavior  => (0x824e, "Undefined behavior"),
+        Portability        => (0x824f, "Portability"),
+        Performance        => (0x8250, "Performance"),
+        Marker             => (0x8268, "Marker"),
+        PushGroup          => (0x8269, "Push group"),
+        PopGroup           => (0x826a, "Pop group"),
+        Other              => (0x8251, "Other"),
+    }
+
+    pub enum DebugSeverity(u32, "Debug severity") {
+        High         => (0x9146, "High"),
+        Medium       => (0x9147, "Medium"),
+        Low          => (0x9148, "Low"),
         Notification => (0x826b, "Notification"),
     }
 }
 
+/// Action taken when a high severity debug message is received.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DebugPolicy {
+    /// Only forward the message to the debug callback.
+    #[default]
+    Log,
+
+    /// Panic printing a backtrace. Panicking inside the debug
+    /// callback would abort the process, so the panic is deferred
+    /// until the GL function that generated the message returns.
+    /// Asynchronous messages may be reported by a later call unless
+    /// [`DEBUG_OUTPUT_SYNCHRONOUS`] is enabled.
+    PanicOnError,
+
+    /// Trigger a debugger breakpoint. If no debugger is attached,
+    /// it behaves like [`DebugPolicy::PanicOnError`]. Debuggers are
+    /// only detected on Linux and Windows.
+    BreakOnError,
+}
+
 /// Selects active texture unit.
 pub fn active_texture(texture_unit: u32) {
     unsafe { ffi::glActiveTexture(texture_unit) }
@@ -310,16 +1744,96 @@ pub fn bind_buffer(target: u32, buffer: Buffer) {
     unsafe { ffi::glBindBuffer(target, buffer.0) }
 }
 
+/// Binds a framebuffer to a framebuffer target.
+pub fn bind_framebuffer(target: u32, framebuffer: Framebuffer) {
+    unsafe { ffi::glBindFramebuffer(target, framebuffer.0) }
+}
+
+/// Binds a level of a texture to an image unit, so it can be read
+/// and written by shaders with image load and store operations. If
+/// `layered` is false, only the layer `layer` of array, cube map and
+/// 3D textures is bound. `access` must be [`READ_ONLY`],
+/// [`WRITE_ONLY`] or [`READ_WRITE`]. `format` is the sized format
+/// used to interpret the texels, like [`RGBA8`] or [`RGBA32F`].
+pub fn bind_image_texture(
+    unit: u32,
+    texture: Texture,
+    level: i32,
+    layered: bool,
+    layer: i32,
+    access: u32,
+    format: u32,
+) {
+    let layered = if layered { 1 } else { 0 };
+    unsafe { ffi::glBindImageTexture(unit, texture.0, level, layered, layer, access, format) }
+}
+
+/// Binds a named renderbuffer object.
+pub fn bind_renderbuffer(target: u32, renderbuffer: Renderbuffer) {
+    unsafe { ffi::glBindRenderbuffer(target, renderbuffer.0) }
+}
+
 /// Binds a named texture to a texturing target.
 pub fn bind_texture(target: u32, texture: Texture) {
     unsafe { ffi::glBindTexture(target, texture.0) }
 }
 
+/// Binds a texture to a texture unit.
+pub fn bind_texture_unit(unit: u32, texture: Texture) {
+    unsafe { ffi::glBindTextureUnit(unit, texture.0) }
+}
+
 /// Binds a vertex array object.
 pub fn bind_vertex_array(array: VertexArray) {
     unsafe { ffi::glBindVertexArray(array.0) }
 }
 
+/// Sets the blend color.
+pub fn blend_color(red: f32, green: f32, blue: f32, alpha: f32) {
+    unsafe { ffi::glBlendColor(red, green, blue, alpha) }
+}
+
+/// Specifies the equation used for both the RGB blend equation and
+/// the alpha blend equation.
+pub fn blend_equation(mode: u32) {
+    unsafe { ffi::glBlendEquation(mode) }
+}
+
+/// Specifies pixel arithmetic.
+pub fn blend_func(sfactor: u32, dfactor: u32) {
+    unsafe { ffi::glBlendFunc(sfactor, dfactor) }
+}
+
+/// Specifies pixel arithmetic for RGB and alpha components
+/// separately.
+pub fn blend_func_separate(src_rgb: u32, dst_rgb: u32, src_alpha: u32, dst_alpha: u32) {
+    unsafe { ffi::glBlendFuncSeparate(src_rgb, dst_rgb, src_alpha, dst_alpha) }
+}
+
+/// Copies a block of pixels from the framebuffer bound to
+/// [`READ_FRAMEBUFFER`] to the framebuffer bound to
+/// [`DRAW_FRAMEBUFFER`]. `src` and `dst` are rectangles specified as
+/// `[x0, y0, x1, y1]`. `mask` is a combination of
+/// [`COLOR_BUFFER_BIT`], [`DEPTH_BUFFER_BIT`] and
+/// [`STENCIL_BUFFER_BIT`]. `filter` must be [`NEAREST`] or [`LINEAR`].
+/// It can be used to resolve multisample framebuffers.
+pub fn blit_framebuffer(src: [i32; 4], dst: [i32; 4], mask: u32, filter: i32) {
+    unsafe {
+        ffi::glBlitFramebuffer(
+            src[0],
+            src[1],
+            src[2],
+            src[3],
+            dst[0],
+            dst[1],
+            dst[2],
+            dst[3],
+            mask,
+            filter as ffi::GLenum,
+        )
+    }
+}
+
 /// Creates and initializes a buffer object's data store.
 pub fn buffer_data<T>(target: u32, data: &[T], usage: u32) {
     unsafe {
@@ -332,6 +1846,49 @@ pub fn buffer_data<T>(target: u32, data: &[T], usage: u32) {
     }
 }
 
+/// Creates and initializes the data store of the buffer object bound
+/// to [`ARRAY_BUFFER`] from a slice of vertex structures, and
+/// describes their layout to the vertex array object currently bound
+/// using [`vertex_attrib_pointers`].
+pub fn buffer_data_structs<V: Vertex>(data: &[V], usage: u32) {
+    buffer_data(ARRAY_BUFFER, data, usage);
+    vertex_attrib_pointers::<V>();
+}
+
+/// Creates the immutable data store of the buffer object bound to
+/// `target`. `size` is specified in bytes. If `data` is provided, it
+/// is used to initialize the data store.
+pub fn buffer_storage<T>(target: u32, size: usize, data: Option<&[T]>, flags: u32) {
+    let data = data.map_or(ptr::null(), |data| data.as_ptr() as *const c_void);
+    unsafe { ffi::glBufferStorage(target, size, data, flags) }
+}
+
+/// Updates a subset of a buffer object's data store. `offset` is
+/// specified in bytes.
+pub fn buffer_sub_data<T>(target: u32, offset: usize, data: &[T]) {
+    unsafe {
+        ffi::glBufferSubData(
+            target,
+            offset as ffi::GLintptr,
+            mem::size_of_val(data),
+            data.as_ptr() as *const c_void,
+        )
+    }
+}
+
+/// Checks the completeness status of a framebuffer. It returns
+/// [`FRAMEBUFFER_COMPLETE`] if the framebuffer is complete.
+pub fn check_framebuffer_status(target: u32) -> u32 {
+    unsafe { ffi::glCheckFramebufferStatus(target) }
+}
+
+/// Checks the completeness status of a named framebuffer when treated
+/// as `target`. It returns [`FRAMEBUFFER_COMPLETE`] if the
+/// framebuffer is complete.
+pub fn check_named_framebuffer_status(framebuffer: Framebuffer, target: u32) -> u32 {
+    unsafe { ffi::glCheckNamedFramebufferStatus(framebuffer.0, target) }
+}
+
 /// Clears buffers to preset values.
 pub fn clear(mask: u32) {
     unsafe { ffi::glClear(mask) }
@@ -342,29 +1899,107 @@ pub fn clear_color(red: f32, green: f32, blue: f32, alpha: f32) {
     unsafe { ffi::glClearColor(red, green, blue, alpha) }
 }
 
+/// Specifies the clear value for the depth buffer.
+pub fn clear_depth(depth: f64) {
+    unsafe { ffi::glClearDepth(depth) }
+}
+
+/// Specifies the clear value for the stencil buffer.
+pub fn clear_stencil(s: i32) {
+    unsafe { ffi::glClearStencil(s) }
+}
+
+/// Blocks until a sync object is signaled or `timeout` nanoseconds
+/// pass. It returns [`ALREADY_SIGNALED`], [`TIMEOUT_EXPIRED`],
+/// [`CONDITION_SATISFIED`] or [`WAIT_FAILED`].
+pub fn client_wait_sync(sync: SyncObject, flags: u32, timeout: u64) -> u32 {
+    unsafe { ffi::glClientWaitSync(sync.0, flags, timeout) }
+}
+
 /// Compiles a shader object.
 pub fn compile_shader(shader: Shader) {
     unsafe { ffi::glCompileShader(shader.0) }
 }
 
+/// Creates buffer objects.
+pub fn create_buffers(n: usize) -> Vec<Buffer> {
+    let mut buffers = vec![Buffer::zero(); n];
+    unsafe { ffi::glCreateBuffers(n as ffi::GLsizei, buffers.as_mut_ptr() as *mut ffi::GLuint) };
+    buffers
+}
+
+/// Creates framebuffer objects.
+pub fn create_framebuffers(n: usize) -> Vec<Framebuffer> {
+    let mut framebuffers = vec![Framebuffer::zero(); n];
+    unsafe {
+        ffi::glCreateFramebuffers(
+            n as ffi::GLsizei,
+            framebuffers.as_mut_ptr() as *mut ffi::GLuint,
+        )
+    };
+    framebuffers
+}
+
 /// Creates a program object.
 pub fn create_program() -> Program {
     let program = unsafe { ffi::glCreateProgram() };
     Program(program)
 }
 
+/// Creates renderbuffer objects.
+pub fn create_renderbuffers(n: usize) -> Vec<Renderbuffer> {
+    let mut renderbuffers = vec![Renderbuffer::zero(); n];
+    unsafe {
+        ffi::glCreateRenderbuffers(
+            n as ffi::GLsizei,
+            renderbuffers.as_mut_ptr() as *mut ffi::GLuint,
+        )
+    };
+    renderbuffers
+}
+
 /// Creates a shader object.
 pub fn create_shader(typ: u32) -> Shader {
     let shader = unsafe { ffi::glCreateShader(typ) };
     Shader(shader)
 }
 
+/// Creates texture objects with the specified target.
+pub fn create_textures(target: u32, n: usize) -> Vec<Texture> {
+    let mut textures = vec![Texture::zero(); n];
+    unsafe {
+        ffi::glCreateTextures(
+            target,
+            n as ffi::GLsizei,
+            textures.as_mut_ptr() as *mut ffi::GLuint,
+        )
+    };
+    textures
+}
+
+/// Creates vertex array objects.
+pub fn create_vertex_arrays(n: usize) -> Vec<VertexArray> {
+    let mut arrays = vec![VertexArray::zero(); n];
+    unsafe {
+        ffi::glCreateVertexArrays(n as ffi::GLsizei, arrays.as_mut_ptr() as *mut ffi::GLuint)
+    };
+    arrays
+}
+
 /// Debug callback.
 pub type FnDebug =
     fn(source: DebugSource, typ: DebugType, id: u32, severity: DebugSeverity, message: &str);
 
 static DEBUG_CALLBACK: Mutex<Option<FnDebug>> = Mutex::new(None);
 
+static DEBUG_POLICY: Mutex<DebugPolicy> = Mutex::new(DebugPolicy::Log);
+
+static ERROR_CHECKS: AtomicBool = AtomicBool::new(false);
+
+static PENDING_PANIC: AtomicBool = AtomicBool::new(false);
+
+static PENDING_PANIC_MESSAGE: Mutex<Option<String>> = Mutex::new(None);
+
 extern "C" fn debug_callback(
     source: ffi::GLenum,
     typ: ffi::GLenum,
@@ -381,7 +2016,106 @@ extern "C" fn debug_callback(
     let message = unsafe { CStr::from_ptr(message) }
         .to_str()
         .expect("GL error message is not a valid UTF-8 string");
+    let is_high = matches!(DebugSeverity::from(severity), DebugSeverity::High);
     cb(source.into(), typ.into(), id, severity.into(), message);
+
+    if !cfg!(debug_assertions) || !is_high {
+        return;
+    }
+    match *DEBUG_POLICY.lock().unwrap() {
+        DebugPolicy::Log => {}
+        DebugPolicy::BreakOnError if debugger_attached() => debug_break(),
+        DebugPolicy::PanicOnError | DebugPolicy::BreakOnError => {
+            // Unwinding out of an extern "C" function aborts the
+            // process, so the panic is raised by the caller.
+            let message = format!(
+                "high severity GL debug message: {message} ({id})\n{}",
+                Backtrace::force_capture()
+            );
+            PENDING_PANIC_MESSAGE.lock().unwrap().get_or_insert(message);
+            PENDING_PANIC.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Raises the panic deferred by the debug callback.
+fn pending_panic() {
+    PENDING_PANIC.store(false, Ordering::Relaxed);
+    if let Some(message) = PENDING_PANIC_MESSAGE.lock().unwrap().take() {
+        panic!("{message}");
+    }
+}
+
+/// Reports the errors recorded by the GL after calling the function
+/// `name` with the arguments `args`.
+fn check_error(name: &str, args: &[&dyn fmt::Debug]) {
+    loop {
+        let err = unsafe { ffi::glGetError() };
+        if err == NO_ERROR {
+            return;
+        }
+
+        let args = args
+            .iter()
+            .map(|arg| format!("{arg:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let message = format!("GL error {err:#06x} in {name}({args})");
+        eprintln!("{message}");
+
+        if !cfg!(debug_assertions) {
+            continue;
+        }
+        match *DEBUG_POLICY.lock().unwrap() {
+            DebugPolicy::Log => {}
+            DebugPolicy::BreakOnError if debugger_attached() => debug_break(),
+            DebugPolicy::PanicOnError | DebugPolicy::BreakOnError => {
+                panic!("{message}\n{}", Backtrace::force_capture());
+            }
+        }
+    }
+}
+
+/// Reports whether a debugger is attached to the process.
+fn debugger_attached() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string("/proc/self/status")
+            .ok()
+            .and_then(|status| {
+                status
+                    .lines()
+                    .find_map(|line| line.strip_prefix("TracerPid:"))
+                    .map(|pid| pid.trim() != "0")
+            })
+            .unwrap_or(false)
+    }
+    #[cfg(windows)]
+    {
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn IsDebuggerPresent() -> i32;
+        }
+        unsafe { IsDebuggerPresent() != 0 }
+    }
+    #[cfg(not(any(target_os = "linux", windows)))]
+    {
+        false
+    }
+}
+
+/// Traps into the attached debugger.
+fn debug_break() {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    unsafe {
+        std::arch::asm!("int3")
+    };
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        std::arch::asm!("brk #0xf000")
+    };
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+    std::process::abort();
 }
 
 /// Specifies a callback to receive debugging messages from the GL.
@@ -393,107 +2127,564 @@ pub fn debug_message_callback(callback: FnDebug) {
 /// Deletes named buffer objects.
 pub fn delete_buffers(buffers: &[Buffer]) {
     unsafe {
-        ffi::glDeleteBuffers(
-            buffers.len() as ffi::GLsizei,
-            buffers.as_ptr() as *const ffi::GLuint,
+        ffi::glDeleteBuffers(
+            buffers.len() as ffi::GLsizei,
+            buffers.as_ptr() as *const ffi::GLuint,
+        )
+    }
+}
+
+/// Deletes named framebuffer objects.
+pub fn delete_framebuffers(framebuffers: &[Framebuffer]) {
+    unsafe {
+        ffi::glDeleteFramebuffers(
+            framebuffers.len() as ffi::GLsizei,
+            framebuffers.as_ptr() as *const ffi::GLuint,
+        )
+    }
+}
+
+/// Deletes a program object.
+pub fn delete_program(program: Program) {
+    unsafe { ffi::glDeleteProgram(program.0) }
+}
+
+/// Deletes named renderbuffer objects.
+pub fn delete_renderbuffers(renderbuffers: &[Renderbuffer]) {
+    unsafe {
+        ffi::glDeleteRenderbuffers(
+            renderbuffers.len() as ffi::GLsizei,
+            renderbuffers.as_ptr() as *const ffi::GLuint,
+        )
+    }
+}
+
+/// Deletes a shader object.
+pub fn delete_shader(shader: Shader) {
+    unsafe { ffi::glDeleteShader(shader.0) }
+}
+
+/// Deletes a sync object.
+pub fn delete_sync(sync: SyncObject) {
+    unsafe { ffi::glDeleteSync(sync.0) }
+}
+
+/// Delete named textures.
+pub fn delete_textures(textures: &[Texture]) {
+    unsafe {
+        ffi::glDeleteTextures(
+            textures.len() as ffi::GLsizei,
+            textures.as_ptr() as *const ffi::GLuint,
+        )
+    }
+}
+
+/// Deletes vertex array objects.
+pub fn delete_vertex_arrays(arrays: &[VertexArray]) {
+    unsafe {
+        ffi::glDeleteVertexArrays(
+            arrays.len() as ffi::GLsizei,
+            arrays.as_ptr() as *const ffi::GLuint,
+        )
+    }
+}
+
+/// Specifies the value used for depth buffer comparisons.
+pub fn depth_func(func: u32) {
+    unsafe { ffi::glDepthFunc(func) }
+}
+
+/// Enables or disables writing into the depth buffer.
+pub fn depth_mask(flag: bool) {
+    let flag = if flag { 1 } else { 0 };
+    unsafe { ffi::glDepthMask(flag) }
+}
+
+/// Disables server-side GL capabilities.
+pub fn disable(cap: u32) {
+    unsafe { ffi::glDisable(cap) }
+}
+
+/// Launches compute work groups using the program currently in use.
+pub fn dispatch_compute(num_groups_x: u32, num_groups_y: u32, num_groups_z: u32) {
+    unsafe { ffi::glDispatchCompute(num_groups_x, num_groups_y, num_groups_z) }
+}
+
+/// Renders primitives from array data.
+pub fn draw_arrays(mode: u32, first: i32, count: i32) {
+    unsafe { ffi::glDrawArrays(mode, first, count) }
+}
+
+/// Specifies the color buffers to be drawn into. The fragment
+/// shader output `i` is written into `buffers[i]`, which must be
+/// [`NONE`] or a color attachment like [`COLOR_ATTACHMENT0`].
+pub fn draw_buffers(buffers: &[u32]) {
+    unsafe { ffi::glDrawBuffers(buffers.len() as ffi::GLsizei, buffers.as_ptr()) }
+}
+
+/// Renders primitives from array data using the provided indices.
+pub fn draw_elements(mode: u32, count: usize, typ: u32, indices: usize) {
+    unsafe { ffi::glDrawElements(mode, count as ffi::GLsizei, typ, indices as *const c_void) }
+}
+
+/// Enables server-side GL capabilities.
+pub fn enable(cap: u32) {
+    unsafe { ffi::glEnable(cap) }
+}
+
+/// Enables a generic vertex attribute array of a vertex array
+/// object.
+pub fn enable_vertex_array_attrib(vao: VertexArray, index: u32) {
+    unsafe { ffi::glEnableVertexArrayAttrib(vao.0, index) }
+}
+
+/// Enables a generic vertex attribute array.
+pub fn enable_vertex_attrib_array(index: u32) {
+    unsafe { ffi::glEnableVertexAttribArray(index) }
+}
+
+/// Returns the names of the extensions supported by the GL.
+pub fn extensions() -> Vec<String> {
+    (0..get_integer(NUM_EXTENSIONS).max(0) as u32)
+        .map(|i| get_stringi(EXTENSIONS, i))
+        .collect()
+}
+
+/// Creates a sync object that is signaled when all the preceding
+/// commands are complete.
+pub fn fence_sync() -> SyncObject {
+    SyncObject(unsafe { ffi::glFenceSync(SYNC_GPU_COMMANDS_COMPLETE, 0) })
+}
+
+/// Attaches a renderbuffer as a logical buffer of a framebuffer
+/// object.
+pub fn framebuffer_renderbuffer(
+    target: u32,
+    attachment: u32,
+    renderbuffer_target: u32,
+    renderbuffer: Renderbuffer,
+) {
+    unsafe {
+        ffi::glFramebufferRenderbuffer(target, attachment, renderbuffer_target, renderbuffer.0)
+    }
+}
+
+/// Attaches a level of a texture object as a logical buffer of a
+/// framebuffer object.
+pub fn framebuffer_texture_2d(
+    target: u32,
+    attachment: u32,
+    tex_target: u32,
+    texture: Texture,
+    level: i32,
+) {
+    unsafe { ffi::glFramebufferTexture2D(target, attachment, tex_target, texture.0, level) }
+}
+
+/// Generates buffer object names.
+pub fn gen_buffers(n: usize) -> Vec<Buffer> {
+    let mut buffers = vec![Buffer::zero(); n];
+    unsafe { ffi::glGenBuffers(n as ffi::GLsizei, buffers.as_mut_ptr() as *mut ffi::GLuint) };
+    buffers
+}
+
+/// Generates framebuffer object names.
+pub fn gen_framebuffers(n: usize) -> Vec<Framebuffer> {
+    let mut framebuffers = vec![Framebuffer::zero(); n];
+    unsafe {
+        ffi::glGenFramebuffers(
+            n as ffi::GLsizei,
+            framebuffers.as_mut_ptr() as *mut ffi::GLuint,
+        )
+    };
+    framebuffers
+}
+
+/// Generates renderbuffer object names.
+pub fn gen_renderbuffers(n: usize) -> Vec<Renderbuffer> {
+    let mut renderbuffers = vec![Renderbuffer::zero(); n];
+    unsafe {
+        ffi::glGenRenderbuffers(
+            n as ffi::GLsizei,
+            renderbuffers.as_mut_ptr() as *mut ffi::GLuint,
+        )
+    };
+    renderbuffers
+}
+
+/// Generates texture names.
+pub fn gen_textures(n: usize) -> Vec<Texture> {
+    let mut textures = vec![Texture::zero(); n];
+    unsafe { ffi::glGenTextures(n as ffi::GLsizei, textures.as_mut_ptr() as *mut ffi::GLuint) };
+    textures
+}
+
+/// Generates vertex array object names.
+pub fn gen_vertex_arrays(n: usize) -> Vec<VertexArray> {
+    let mut arrays = vec![VertexArray::zero(); n];
+    unsafe { ffi::glGenVertexArrays(n as ffi::GLsizei, arrays.as_mut_ptr() as *mut ffi::GLuint) };
+    arrays
+}
+
+/// Generates mipmaps for a specified texture object.
+pub fn generate_mipmap(target: u32) {
+    unsafe { ffi::glGenerateMipmap(target) }
+}
+
+/// Generates mipmaps for a texture object.
+pub fn generate_texture_mipmap(texture: Texture) {
+    unsafe { ffi::glGenerateTextureMipmap(texture.0) }
+}
+
+/// Returns the value of the error flag.
+pub fn get_error() -> u32 {
+    unsafe { ffi::glGetError() }
+}
+
+/// Returns the value of a single-valued floating-point parameter.
+pub fn get_float(pname: u32) -> f32 {
+    let mut data = 0.0;
+    unsafe { ffi::glGetFloatv(pname, &mut data) };
+    data
+}
+
+/// Returns the value of a single-valued integer parameter.
+pub fn get_integer(pname: u32) -> i32 {
+    let mut data = 0;
+    unsafe { ffi::glGetIntegerv(pname, &mut data) };
+    data
+}
+
+/// Returns the value of an indexed integer parameter.
+pub fn get_integer_i(pname: u32, index: u32) -> i32 {
+    let mut data = 0;
+    unsafe { ffi::glGetIntegeri_v(pname, index, &mut data) };
+    data
+}
+
+/// Returns a parameter from a program object.
+pub fn get_program(program: Program, pname: u32) -> i32 {
+    let mut param = 0;
+    unsafe { ffi::glGetProgramiv(program.0, pname, &mut param) };
+    param
+}
+
+/// Returns the information log of a program object.
+pub fn get_program_info_log(program: Program) -> String {
+    let len = get_program(program, INFO_LOG_LENGTH);
+    let mut buf = vec![0u8; len.max(1) as usize];
+    let mut written = 0;
+    unsafe {
+        ffi::glGetProgramInfoLog(
+            program.0,
+            buf.len() as ffi::GLsizei,
+            &mut written,
+            buf.as_mut_ptr() as *mut ffi::GLchar,
+        )
+    };
+    buf.truncate(written as usize);
+    String::from_utf8_lossy(&buf).into_owned()
+}
+
+/// Returns a parameter from a shader object.
+pub fn get_shader(shader: Shader, pname: u32) -> i32 {
+    let mut param = 0;
+    unsafe { ffi::glGetShaderiv(shader.0, pname, &mut param) };
+    param
+}
+
+/// Returns the information log of a shader object.
+pub fn get_shader_info_log(shader: Shader) -> String {
+    let len = get_shader(shader, INFO_LOG_LENGTH);
+    let mut buf = vec![0u8; len.max(1) as usize];
+    let mut written = 0;
+    unsafe {
+        ffi::glGetShaderInfoLog(
+            shader.0,
+            buf.len() as ffi::GLsizei,
+            &mut written,
+            buf.as_mut_ptr() as *mut ffi::GLchar,
+        )
+    };
+    buf.truncate(written as usize);
+    String::from_utf8_lossy(&buf).into_owned()
+}
+
+/// Returns a string describing the current GL connection. An empty
+/// string is returned if `name` is not valid.
+pub fn get_string(name: u32) -> String {
+    let s = unsafe { ffi::glGetString(name) };
+    if s.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(s as *const ffi::GLchar) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Returns the string at `index` of the indexed string `name` (e.g.
+/// [`EXTENSIONS`]). An empty string is returned if `name` or `index`
+/// are not valid.
+pub fn get_stringi(name: u32, index: u32) -> String {
+    let s = unsafe { ffi::glGetStringi(name, index) };
+    if s.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(s as *const ffi::GLchar) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Returns the location of a uniform variable.
+pub fn get_uniform_location(program: Program, name: &str) -> Result<UniformLocation> {
+    let cname = CString::new(name)?;
+    let loc = unsafe { ffi::glGetUniformLocation(program.0, cname.as_ptr()) };
+    if loc == -1 {
+        return Err(Error::NonActiveUniform(name.into()));
+    }
+    Ok(UniformLocation(loc))
+}
+
+/// Reports whether the GL supports the extension `name`, like
+/// `GL_ARB_bindless_texture`.
+pub fn has_extension(name: &str) -> bool {
+    (0..get_integer(NUM_EXTENSIONS).max(0) as u32).any(|i| get_stringi(EXTENSIONS, i) == name)
+}
+
+/// Specifies the width of rasterized lines.
+pub fn line_width(width: f32) {
+    unsafe { ffi::glLineWidth(width) }
+}
+
+/// Links a program object.
+pub fn link_program(program: Program) {
+    unsafe { ffi::glLinkProgram(program.0) }
+}
+
+/// Resolves all the GL entry points using the current context. It
+/// must be called after making a context current. Otherwise, the
+/// entry points are resolved the first time they are called and a
+/// missing function causes a panic. See [`load_with`].
+pub fn load_all() -> Result<Vec<&'static str>> {
+    load_with(|name| glfw::get_proc_address(name).ok())
+}
+
+/// Resolves all the GL entry points using `loader`, which returns the
+/// address of the requested function. All the functions are
+/// attempted. It returns an error listing the unavailable functions
+/// if any OpenGL 3.3 core function is missing. Otherwise, it returns
+/// the unavailable functions that require a newer version, such as
+/// direct state access or compute shaders, which must not be called
+/// on the current context.
+pub fn load_with<F: FnMut(&str) -> Option<glfw::GlProc>>(
+    mut loader: F,
+) -> Result<Vec<&'static str>> {
+    let (optional, required): (Vec<_>, Vec<_>) = ffi::load(&mut loader)
+        .into_iter()
+        .partition(|name| OPTIONAL_FUNCTIONS.contains(name));
+    if !required.is_empty() {
+        return Err(Error::MissingFunctions(required));
+    }
+    Ok(optional)
+}
+
+/// GL functions that are not part of the OpenGL 3.3 core profile.
+const OPTIONAL_FUNCTIONS: &[&str] = &[
+    "glBindImageTexture",
+    "glBindTextureUnit",
+    "glBufferStorage",
+    "glCheckNamedFramebufferStatus",
+    "glCreateBuffers",
+    "glCreateFramebuffers",
+    "glCreateRenderbuffers",
+    "glCreateTextures",
+    "glCreateVertexArrays",
+    "glDebugMessageCallback",
+    "glDispatchCompute",
+    "glEnableVertexArrayAttrib",
+    "glGenerateTextureMipmap",
+    "glMemoryBarrier",
+    "glNamedBufferData",
+    "glNamedBufferStorage",
+    "glNamedBufferSubData",
+    "glNamedFramebufferRenderbuffer",
+    "glNamedFramebufferTexture",
+    "glNamedRenderbufferStorage",
+    "glScissorIndexed",
+    "glTexStorage2D",
+    "glTextureParameteri",
+    "glTextureStorage2D",
+    "glTextureSubImage2D",
+    "glVertexArrayAttribBinding",
+    "glVertexArrayAttribFormat",
+    "glVertexArrayElementBuffer",
+    "glVertexArrayVertexBuffer",
+    "glViewportArrayv",
+    "glViewportIndexedf",
+];
+
+/// Maps the entire data store of the buffer object bound to `target`
+/// into the client's address space. It returns a null pointer on
+/// error. See [`with_mapped_buffer_range`] for a safe alternative.
+pub fn map_buffer(target: u32, access: u32) -> *mut c_void {
+    unsafe { ffi::glMapBuffer(target, access) }
+}
+
+/// Maps a section of the data store of the buffer object bound to
+/// `target` into the client's address space. `offset` and `length`
+/// are specified in bytes. It returns a null pointer on error. See
+/// [`with_mapped_buffer_range`] for a safe alternative.
+pub fn map_buffer_range(target: u32, offset: usize, length: usize, access: u32) -> *mut c_void {
+    unsafe { ffi::glMapBufferRange(target, offset as ffi::GLintptr, length, access) }
+}
+
+/// Defines a barrier ordering memory transactions. It must be called
+/// between a shader that writes an image and the commands that read
+/// it.
+pub fn memory_barrier(barriers: u32) {
+    unsafe { ffi::glMemoryBarrier(barriers) }
+}
+
+/// Creates and initializes a named buffer object's data store.
+pub fn named_buffer_data<T>(buffer: Buffer, data: &[T], usage: u32) {
+    unsafe {
+        ffi::glNamedBufferData(
+            buffer.0,
+            mem::size_of_val(data),
+            data.as_ptr() as *const c_void,
+            usage,
         )
     }
 }
 
-/// Deletes a program object.
-pub fn delete_program(program: Program) {
-    unsafe { ffi::glDeleteProgram(program.0) }
-}
-
-/// Deletes a shader object.
-pub fn delete_shader(shader: Shader) {
-    unsafe { ffi::glDeleteShader(shader.0) }
+/// Creates the immutable data store of a named buffer object. `size`
+/// is specified in bytes. If `data` is provided, it is used to
+/// initialize the data store.
+pub fn named_buffer_storage<T>(buffer: Buffer, size: usize, data: Option<&[T]>, flags: u32) {
+    let data = data.map_or(ptr::null(), |data| data.as_ptr() as *const c_void);
+    unsafe { ffi::glNamedBufferStorage(buffer.0, size, data, flags) }
 }
 
-/// Delete named textures.
-pub fn delete_textures(textures: &[Texture]) {
+/// Updates a subset of a named buffer object's data store. `offset`
+/// is specified in bytes.
+pub fn named_buffer_sub_data<T>(buffer: Buffer, offset: usize, data: &[T]) {
     unsafe {
-        ffi::glDeleteTextures(
-            textures.len() as ffi::GLsizei,
-            textures.as_ptr() as *const ffi::GLuint,
+        ffi::glNamedBufferSubData(
+            buffer.0,
+            offset as ffi::GLintptr,
+            mem::size_of_val(data),
+            data.as_ptr() as *const c_void,
         )
     }
 }
 
-/// Deletes vertex array objects.
-pub fn delete_vertex_arrays(arrays: &[VertexArray]) {
+/// Attaches a renderbuffer as a logical buffer of a named
+/// framebuffer.
+pub fn named_framebuffer_renderbuffer(
+    framebuffer: Framebuffer,
+    attachment: u32,
+    renderbuffer_target: u32,
+    renderbuffer: Renderbuffer,
+) {
     unsafe {
-        ffi::glDeleteVertexArrays(
-            arrays.len() as ffi::GLsizei,
-            arrays.as_ptr() as *const ffi::GLuint,
+        ffi::glNamedFramebufferRenderbuffer(
+            framebuffer.0,
+            attachment,
+            renderbuffer_target,
+            renderbuffer.0,
         )
     }
 }
 
-/// Renders primitives from array data.
-pub fn draw_arrays(mode: u32, first: i32, count: i32) {
-    unsafe { ffi::glDrawArrays(mode, first, count) }
+/// Attaches a level of a texture object as a logical buffer of a
+/// named framebuffer.
+pub fn named_framebuffer_texture(
+    framebuffer: Framebuffer,
+    attachment: u32,
+    texture: Texture,
+    level: i32,
+) {
+    unsafe { ffi::glNamedFramebufferTexture(framebuffer.0, attachment, texture.0, level) }
 }
 
-/// Renders primitives from array data using the provided indices.
-pub fn draw_elements(mode: u32, count: usize, typ: u32, indices: usize) {
-    unsafe { ffi::glDrawElements(mode, count as ffi::GLsizei, typ, indices as *const c_void) }
+/// Establishes data storage, format and dimensions of a named
+/// renderbuffer object's image.
+pub fn named_renderbuffer_storage(
+    renderbuffer: Renderbuffer,
+    internal_format: u32,
+    width: i32,
+    height: i32,
+) {
+    unsafe { ffi::glNamedRenderbufferStorage(renderbuffer.0, internal_format, width, height) }
 }
 
-/// Enables server-side GL capabilities.
-pub fn enable(cap: u32) {
-    unsafe { ffi::glEnable(cap) }
+/// Sets pixel storage modes.
+pub fn pixel_store(pname: u32, param: i32) {
+    unsafe { ffi::glPixelStorei(pname, param) }
 }
 
-/// Enables a generic vertex attribute array.
-pub fn enable_vertex_attrib_array(index: u32) {
-    unsafe { ffi::glEnableVertexAttribArray(index) }
+/// Specifies the diameter of rasterized points.
+pub fn point_size(size: f32) {
+    unsafe { ffi::glPointSize(size) }
 }
 
-/// Generates buffer object names.
-pub fn gen_buffers(n: usize) -> Vec<Buffer> {
-    let mut buffers = vec![Buffer::zero(); n];
-    unsafe { ffi::glGenBuffers(n as ffi::GLsizei, buffers.as_mut_ptr() as *mut ffi::GLuint) };
-    buffers
+/// Sets the scale and units used to calculate depth values. It is
+/// typically used with [`POLYGON_OFFSET_FILL`] to avoid shadow acne
+/// when rendering shadow maps.
+pub fn polygon_offset(factor: f32, units: f32) {
+    unsafe { ffi::glPolygonOffset(factor, units) }
 }
 
-/// Generates texture names.
-pub fn gen_textures(n: usize) -> Vec<Texture> {
-    let mut textures = vec![Texture::zero(); n];
-    unsafe { ffi::glGenTextures(n as ffi::GLsizei, textures.as_mut_ptr() as *mut ffi::GLuint) };
-    textures
+/// Selects the color buffer of the framebuffer bound to
+/// [`READ_FRAMEBUFFER`] used as source for pixel reads and blits.
+pub fn read_buffer(src: u32) {
+    unsafe { ffi::glReadBuffer(src) }
 }
 
-/// Generates vertex array object names.
-pub fn gen_vertex_arrays(n: usize) -> Vec<VertexArray> {
-    let mut arrays = vec![VertexArray::zero(); n];
-    unsafe { ffi::glGenVertexArrays(n as ffi::GLsizei, arrays.as_mut_ptr() as *mut ffi::GLuint) };
-    arrays
+/// Establishes data storage, format and dimensions of a renderbuffer
+/// object's image.
+pub fn renderbuffer_storage(target: u32, internal_format: u32, width: i32, height: i32) {
+    unsafe { ffi::glRenderbufferStorage(target, internal_format, width, height) }
 }
 
-/// Generates mipmaps for a specified texture object.
-pub fn generate_mipmap(target: u32) {
-    unsafe { ffi::glGenerateMipmap(target) }
+/// Establishes data storage, format, dimensions and sample count of a
+/// renderbuffer object's image.
+pub fn renderbuffer_storage_multisample(
+    target: u32,
+    samples: i32,
+    internal_format: u32,
+    width: i32,
+    height: i32,
+) {
+    unsafe {
+        ffi::glRenderbufferStorageMultisample(target, samples, internal_format, width, height)
+    }
 }
 
-/// Returns the value of the error flag.
-pub fn get_error() -> u32 {
-    unsafe { ffi::glGetError() }
+/// Defines the scissor box.
+pub fn scissor(x: i32, y: i32, width: i32, height: i32) {
+    unsafe { ffi::glScissor(x, y, width, height) }
 }
 
-/// Returns the location of a uniform variable.
-pub fn get_uniform_location(program: Program, name: &str) -> Result<UniformLocation> {
-    let cname = CString::new(name)?;
-    let loc = unsafe { ffi::glGetUniformLocation(program.0, cname.as_ptr()) };
-    if loc == -1 {
-        return Err(Error::NonActiveUniform(name.into()));
-    }
-    Ok(UniformLocation(loc))
+/// Defines the scissor box of the viewport `index`.
+pub fn scissor_indexed(index: u32, left: i32, bottom: i32, width: i32, height: i32) {
+    unsafe { ffi::glScissorIndexed(index, left, bottom, width, height) }
 }
 
-/// Links a program object.
-pub fn link_program(program: Program) {
-    unsafe { ffi::glLinkProgram(program.0) }
+/// Sets the action taken when a high severity message is received by
+/// the debug callback set with [`debug_message_callback`]. The
+/// policy is ignored in release builds.
+pub fn set_debug_policy(policy: DebugPolicy) {
+    *DEBUG_POLICY.lock().unwrap() = policy;
+}
+
+/// Enables or disables checking the error flag after every GL call.
+/// Errors are printed to the standard error output along with the
+/// name and the arguments of the failing function and, in debug
+/// builds, the debug policy is applied. It is useful on drivers that
+/// do not support [`DEBUG_OUTPUT`].
+pub fn set_error_checks(enabled: bool) {
+    ERROR_CHECKS.store(enabled, Ordering::Relaxed);
 }
 
 /// Replaces the source code in a shader object.
@@ -516,13 +2707,46 @@ pub fn shader_source(shader: Shader, sources: &[&str]) -> Result<()> {
     Ok(())
 }
 
-/// Specifies a two-dimensional texture image.
+/// Sets front and back function and reference value for stencil
+/// testing.
+pub fn stencil_func(func: u32, reference: i32, mask: u32) {
+    unsafe { ffi::glStencilFunc(func, reference, mask) }
+}
+
+/// Controls the front and back writing of individual bits in the
+/// stencil planes.
+pub fn stencil_mask(mask: u32) {
+    unsafe { ffi::glStencilMask(mask) }
+}
+
+/// Sets front and back stencil test actions.
+pub fn stencil_op(sfail: u32, dpfail: u32, dppass: u32) {
+    unsafe { ffi::glStencilOp(sfail, dpfail, dppass) }
+}
+
+/// Specifies a two-dimensional texture image. The dimensions of the
+/// texture are taken from `image`, whose number of channels must
+/// match `format`.
 pub fn tex_image_2d(
     target: u32,
     level: i32,
     internal_format: u32,
     image: &stb_image::Image,
     format: u32,
+) -> Result<()> {
+    check_image_format(image, format)?;
+    upload_image_2d(target, level, internal_format, image, format);
+    Ok(())
+}
+
+/// Specifies a two-dimensional texture image without validating its
+/// format.
+fn upload_image_2d(
+    target: u32,
+    level: i32,
+    internal_format: u32,
+    image: &stb_image::Image,
+    format: u32,
 ) {
     unsafe {
         ffi::glTexImage2D(
@@ -539,6 +2763,145 @@ pub fn tex_image_2d(
     }
 }
 
+/// Checks that the number of channels of `image` matches `format`.
+/// Formats with an unknown number of components are not validated.
+fn check_image_format(image: &stb_image::Image, format: u32) -> Result<()> {
+    let channels = match format {
+        RED => 1,
+        RG => 2,
+        RGB => 3,
+        RGBA => 4,
+        _ => return Ok(()),
+    };
+    if image.channels() != channels {
+        return Err(Error::FormatMismatch {
+            format,
+            channels: image.channels(),
+        });
+    }
+    Ok(())
+}
+
+/// Specifies a two-dimensional texture image from an imported image.
+/// The format and internal format are derived from the number of
+/// channels and the import options of the image. Mipmaps are
+/// generated if requested.
+pub fn tex_image_2d_import(target: u32, image: &stb_image::Image) {
+    let options = image.options();
+    let (internal_format, format) = match (image.channels(), options.srgb) {
+        (1, _) => (RED, RED),
+        (2, _) => (RG, RG),
+        (3, false) => (RGB, RGB),
+        (3, true) => (SRGB8, RGB),
+        (_, false) => (RGBA, RGBA),
+        (_, true) => (SRGB8_ALPHA8, RGBA),
+    };
+    pixel_store(UNPACK_ALIGNMENT, 1);
+    upload_image_2d(target, 0, internal_format, image, format);
+    if options.generate_mipmaps {
+        generate_mipmap(target);
+    }
+}
+
+/// Establishes the data storage, format, dimensions and number of
+/// samples of a multisample texture's image.
+pub fn tex_image_2d_multisample(
+    target: u32,
+    samples: i32,
+    internal_format: u32,
+    width: i32,
+    height: i32,
+    fixed_sample_locations: bool,
+) {
+    let fixed_sample_locations = if fixed_sample_locations { 1 } else { 0 };
+    unsafe {
+        ffi::glTexImage2DMultisample(
+            target,
+            samples,
+            internal_format,
+            width,
+            height,
+            fixed_sample_locations,
+        )
+    }
+}
+
+/// Specifies a three-dimensional texture image. `size` is specified
+/// as `[width, height, depth]`. If `data` is provided, it is used to
+/// initialize the texture using the pixel `format` and `typ`.
+pub fn tex_image_3d<T>(
+    target: u32,
+    level: i32,
+    internal_format: u32,
+    size: [i32; 3],
+    format: u32,
+    typ: u32,
+    data: Option<&[T]>,
+) {
+    let data = data.map_or(ptr::null(), |data| data.as_ptr() as *const c_void);
+    unsafe {
+        ffi::glTexImage3D(
+            target,
+            level,
+            internal_format as ffi::GLint,
+            size[0],
+            size[1],
+            size[2],
+            0,
+            format,
+            typ,
+            data,
+        )
+    }
+}
+
+/// Specifies a three-dimensional texture image from a 3D LUT stored
+/// as a strip image. The strip of a LUT of size `n` is `n * n` pixels
+/// wide and `n` pixels high and contains `n` slices side by side.
+/// Red increases from left to right within every slice, green from
+/// top to bottom and blue from slice to slice, so the image must be
+/// loaded without flipping it vertically. The texture is `n` texels
+/// wide in each dimension and it is usually sampled with [`LINEAR`]
+/// filtering and [`CLAMP_TO_EDGE`] wrapping.
+pub fn tex_image_3d_lut(target: u32, image: &stb_image::Image) -> Result<()> {
+    let (width, height, channels) = (image.width(), image.height(), image.channels());
+    let n = height;
+    if n == 0 || Some(width) != n.checked_mul(n) || !matches!(channels, 3 | 4) {
+        return Err(Error::InvalidLut {
+            width,
+            height,
+            channels,
+        });
+    }
+
+    let row = n * channels;
+    let mut texels = Vec::with_capacity(image.pixels().len());
+    for b in 0..n {
+        for g in 0..n {
+            let start = (g * width + b * n) * channels;
+            texels.extend_from_slice(&image.pixels()[start..start + row]);
+        }
+    }
+
+    let (internal_format, format) = if channels == 3 {
+        (RGB8, RGB)
+    } else {
+        (RGBA8, RGBA)
+    };
+    let n = n as i32;
+    pixel_store(UNPACK_ALIGNMENT, 1);
+    tex_image_3d(
+        target,
+        0,
+        internal_format,
+        [n, n, n],
+        format,
+        UNSIGNED_BYTE,
+        Some(&texels),
+    );
+    Ok(())
+}
+
 /// Sets texture parameters.
 pub fn tex_parameter(target: u32, pname: u32, param: TexParam) {
     match param {
@@ -546,21 +2909,226 @@ pub fn tex_parameter(target: u32, pname: u32, param: TexParam) {
     }
 }
 
+/// Specifies the immutable storage of all the levels of a
+/// two-dimensional texture. `internal_format` must be a sized format,
+/// like [`RGBA8`].
+pub fn tex_storage_2d(target: u32, levels: i32, internal_format: u32, width: i32, height: i32) {
+    unsafe { ffi::glTexStorage2D(target, levels, internal_format, width, height) }
+}
+
+/// Specifies a two-dimensional texture subimage.
+pub fn tex_sub_image_2d(
+    target: u32,
+    level: i32,
+    xoffset: i32,
+    yoffset: i32,
+    image: &stb_image::Image,
+    format: u32,
+) -> Result<()> {
+    check_image_format(image, format)?;
+    unsafe {
+        ffi::glTexSubImage2D(
+            target,
+            level,
+            xoffset,
+            yoffset,
+            image.width() as ffi::GLsizei,
+            image.height() as ffi::GLsizei,
+            format,
+            UNSIGNED_BYTE,
+            image.pixels().as_ptr() as *const c_void,
+        )
+    }
+    Ok(())
+}
+
+/// Specifies a three-dimensional texture subimage. `offset` and
+/// `size` are specified as `[x, y, z]` and `[width, height, depth]`.
+pub fn tex_sub_image_3d<T>(
+    target: u32,
+    level: i32,
+    offset: [i32; 3],
+    size: [i32; 3],
+    format: u32,
+    typ: u32,
+    data: &[T],
+) {
+    unsafe {
+        ffi::glTexSubImage3D(
+            target,
+            level,
+            offset[0],
+            offset[1],
+            offset[2],
+            size[0],
+            size[1],
+            size[2],
+            format,
+            typ,
+            data.as_ptr() as *const c_void,
+        )
+    }
+}
+
+/// Sets texture parameters of a texture object.
+pub fn texture_parameter(texture: Texture, pname: u32, param: TexParam) {
+    match param {
+        TexParam::Int(param) => unsafe { ffi::glTextureParameteri(texture.0, pname, param) },
+    }
+}
+
+/// Specifies the immutable storage of all the levels of a
+/// two-dimensional texture object.
+pub fn texture_storage_2d(
+    texture: Texture,
+    levels: i32,
+    internal_format: u32,
+    width: i32,
+    height: i32,
+) {
+    unsafe { ffi::glTextureStorage2D(texture.0, levels, internal_format, width, height) }
+}
+
+/// Specifies a two-dimensional texture subimage of a texture object.
+pub fn texture_sub_image_2d(
+    texture: Texture,
+    level: i32,
+    xoffset: i32,
+    yoffset: i32,
+    image: &stb_image::Image,
+    format: u32,
+) -> Result<()> {
+    check_image_format(image, format)?;
+    unsafe {
+        ffi::glTextureSubImage2D(
+            texture.0,
+            level,
+            xoffset,
+            yoffset,
+            image.width() as ffi::GLsizei,
+            image.height() as ffi::GLsizei,
+            format,
+            UNSIGNED_BYTE,
+            image.pixels().as_ptr() as *const c_void,
+        )
+    }
+    Ok(())
+}
+
 /// Specify the value of a uniform variable for the current program
 /// object.
 pub fn uniform(location: UniformLocation, uniform: Uniform) {
+    let loc = location.0;
     match uniform {
-        Uniform::Int(v) => unsafe { ffi::glUniform1i(location.0, v) },
-        Uniform::Vec4(v) => unsafe { ffi::glUniform4f(location.0, v[0], v[1], v[2], v[3]) },
-        Uniform::Mat4(v) => unsafe { ffi::glUniformMatrix4fv(location.0, 1, 1, v.as_ptr()) },
+        Uniform::Float(v) => unsafe { ffi::glUniform1f(loc, v) },
+        Uniform::Int(v) => unsafe { ffi::glUniform1i(loc, v) },
+        Uniform::Uint(v) => unsafe { ffi::glUniform1ui(loc, v) },
+        Uniform::Vec2(v) => unsafe { ffi::glUniform2f(loc, v[0], v[1]) },
+        Uniform::Vec3(v) => unsafe { ffi::glUniform3f(loc, v[0], v[1], v[2]) },
+        Uniform::Vec4(v) => unsafe { ffi::glUniform4f(loc, v[0], v[1], v[2], v[3]) },
+        Uniform::IVec2(v) => unsafe { ffi::glUniform2i(loc, v[0], v[1]) },
+        Uniform::IVec3(v) => unsafe { ffi::glUniform3i(loc, v[0], v[1], v[2]) },
+        Uniform::IVec4(v) => unsafe { ffi::glUniform4i(loc, v[0], v[1], v[2], v[3]) },
+        Uniform::UVec2(v) => unsafe { ffi::glUniform2ui(loc, v[0], v[1]) },
+        Uniform::UVec3(v) => unsafe { ffi::glUniform3ui(loc, v[0], v[1], v[2]) },
+        Uniform::UVec4(v) => unsafe { ffi::glUniform4ui(loc, v[0], v[1], v[2], v[3]) },
+        Uniform::Mat2(v) => unsafe { ffi::glUniformMatrix2fv(loc, 1, 1, v.as_ptr()) },
+        Uniform::Mat3(v) => unsafe { ffi::glUniformMatrix3fv(loc, 1, 1, v.as_ptr()) },
+        Uniform::Mat4(v) => unsafe { ffi::glUniformMatrix4fv(loc, 1, 1, v.as_ptr()) },
+        Uniform::FloatArray(v) => unsafe {
+            ffi::glUniform1fv(loc, v.len() as ffi::GLsizei, v.as_ptr())
+        },
+        Uniform::IntArray(v) => unsafe {
+            ffi::glUniform1iv(loc, v.len() as ffi::GLsizei, v.as_ptr())
+        },
+        Uniform::Vec2Array(v) => unsafe {
+            ffi::glUniform2fv(loc, v.len() as ffi::GLsizei, v.as_ptr() as *const f32)
+        },
+        Uniform::Vec3Array(v) => unsafe {
+            ffi::glUniform3fv(loc, v.len() as ffi::GLsizei, v.as_ptr() as *const f32)
+        },
+        Uniform::Vec4Array(v) => unsafe {
+            ffi::glUniform4fv(loc, v.len() as ffi::GLsizei, v.as_ptr() as *const f32)
+        },
+        Uniform::Mat2Array(v) => unsafe {
+            ffi::glUniformMatrix2fv(loc, v.len() as ffi::GLsizei, 1, v.as_ptr() as *const f32)
+        },
+        Uniform::Mat3Array(v) => unsafe {
+            ffi::glUniformMatrix3fv(loc, v.len() as ffi::GLsizei, 1, v.as_ptr() as *const f32)
+        },
+        Uniform::Mat4Array(v) => unsafe {
+            ffi::glUniformMatrix4fv(loc, v.len() as ffi::GLsizei, 1, v.as_ptr() as *const f32)
+        },
     }
 }
 
+/// Releases the mapping of the buffer object bound to `target`. It
+/// returns false if the data store contents became corrupt while the
+/// buffer was mapped.
+pub fn unmap_buffer(target: u32) -> bool {
+    unsafe { ffi::glUnmapBuffer(target) != 0 }
+}
+
 /// Installs a program object as part of current rendering state.
 pub fn use_program(program: Program) {
     unsafe { ffi::glUseProgram(program.0) }
 }
 
+/// Associates a vertex attribute and a vertex buffer binding of a
+/// vertex array object.
+pub fn vertex_array_attrib_binding(vao: VertexArray, attrib_index: u32, binding_index: u32) {
+    unsafe { ffi::glVertexArrayAttribBinding(vao.0, attrib_index, binding_index) }
+}
+
+/// Specifies the organization of a vertex attribute of a vertex array
+/// object. `relative_offset` is specified in bytes.
+pub fn vertex_array_attrib_format(
+    vao: VertexArray,
+    attrib_index: u32,
+    size: usize,
+    typ: u32,
+    normalized: bool,
+    relative_offset: usize,
+) {
+    let normalized = if normalized { 1 } else { 0 };
+    unsafe {
+        ffi::glVertexArrayAttribFormat(
+            vao.0,
+            attrib_index,
+            size as ffi::GLint,
+            typ,
+            normalized,
+            relative_offset as ffi::GLuint,
+        )
+    }
+}
+
+/// Binds a buffer object to the element array buffer bind point of a
+/// vertex array object.
+pub fn vertex_array_element_buffer(vao: VertexArray, buffer: Buffer) {
+    unsafe { ffi::glVertexArrayElementBuffer(vao.0, buffer.0) }
+}
+
+/// Binds a buffer object to a vertex buffer bind point of a vertex
+/// array object. `offset` and `stride` are specified in bytes.
+pub fn vertex_array_vertex_buffer(
+    vao: VertexArray,
+    binding_index: u32,
+    buffer: Buffer,
+    offset: usize,
+    stride: usize,
+) {
+    unsafe {
+        ffi::glVertexArrayVertexBuffer(
+            vao.0,
+            binding_index,
+            buffer.0,
+            offset as ffi::GLintptr,
+            stride as ffi::GLsizei,
+        )
+    }
+}
+
 /// Defines an array of generic vertex attribute data.
 pub fn vertex_attrib_pointer(
     index: u32,
@@ -583,7 +3151,188 @@ pub fn vertex_attrib_pointer(
     }
 }
 
+/// Defines and enables the generic vertex attribute arrays described
+/// by the vertex structure `V`. The data is sourced from the buffer
+/// currently bound to [`ARRAY_BUFFER`].
+pub fn vertex_attrib_pointers<V: Vertex>() {
+    for (i, attrib) in V::ATTRIBS.iter().enumerate() {
+        vertex_attrib_pointer(
+            i as u32,
+            attrib.size,
+            attrib.typ,
+            attrib.normalized,
+            mem::size_of::<V>(),
+            attrib.offset,
+        );
+        enable_vertex_attrib_array(i as u32);
+    }
+}
+
 /// Sets the viewport.
 pub fn viewport(x: i32, y: i32, width: i32, height: i32) {
     unsafe { ffi::glViewport(x, y, width, height) }
 }
+
+/// Sets multiple viewports, starting at the viewport `first`. Every
+/// viewport is specified as `[x, y, width, height]`.
+pub fn viewport_array(first: u32, viewports: &[[f32; 4]]) {
+    unsafe {
+        ffi::glViewportArrayv(
+            first,
+            viewports.len() as ffi::GLsizei,
+            viewports.as_ptr() as *const ffi::GLfloat,
+        )
+    }
+}
+
+/// Sets the viewport `index`. The viewport used by the primitives
+/// can be selected with the `gl_ViewportIndex` variable of the
+/// geometry shader.
+pub fn viewport_indexed(index: u32, x: f32, y: f32, width: f32, height: f32) {
+    unsafe { ffi::glViewportIndexedf(index, x, y, width, height) }
+}
+
+/// Maps `len` elements of type `T`, starting at element `offset`, of
+/// the buffer object bound to `target`, calls `f` with the mapped
+/// slice and releases the mapping. [`MAP_WRITE_BIT`] is always added
+/// to `access`, because the slice is mutable.
+pub fn with_mapped_buffer_range<T: Pod, R>(
+    target: u32,
+    offset: usize,
+    len: usize,
+    access: u32,
+    f: impl FnOnce(&mut [T]) -> R,
+) -> Result<R> {
+    let size = mem::size_of::<T>();
+    let ptr = map_buffer_range(target, offset * size, len * size, access | MAP_WRITE_BIT);
+    if ptr.is_null() {
+        return Err(Error::MapBuffer);
+    }
+    let ret = f(unsafe { slice::from_raw_parts_mut(ptr as *mut T, len) });
+    if !unmap_buffer(target) {
+        return Err(Error::UnmapBuffer);
+    }
+    Ok(ret)
+}
+
+/// GL objects that delete themselves when dropped. Every owned object
+/// dereferences to its handle, so it can be passed to the functions of
+/// the parent module.
+pub mod owned {
+    use std::ops::Deref;
+
+    macro_rules! define_owned {
+        ($(#[$meta:meta])* $name:ident($handle:path), $delete:expr) => {
+            $(#[$meta])*
+            pub struct $name($handle);
+
+            impl $name {
+                /// Takes the ownership of `handle`. It will be
+                /// deleted when the returned object is dropped.
+                pub fn from_handle(handle: $handle) -> $name {
+                    $name(handle)
+                }
+
+                /// Releases the ownership of the object, which will
+                /// not be deleted, and returns its handle.
+                pub fn into_handle(self) -> $handle {
+                    let handle = self.0;
+                    std::mem::forget(self);
+                    handle
+                }
+            }
+
+            impl Deref for $name {
+                type Target = $handle;
+
+                fn deref(&self) -> &$handle {
+                    &self.0
+                }
+            }
+
+            impl Drop for $name {
+                fn drop(&mut self) {
+                    let delete: fn($handle) = $delete;
+                    delete(self.0);
+                }
+            }
+        };
+        ($(#[$meta:meta])* $name:ident($handle:path), $delete:expr, $new_doc:literal, $new:expr) => {
+            define_owned!($(#[$meta])* $name($handle), $delete);
+
+            impl $name {
+                #[doc = $new_doc]
+                pub fn new() -> $name {
+                    $name($new)
+                }
+            }
+
+            impl Default for $name {
+                fn default() -> $name {
+                    $name::new()
+                }
+            }
+        };
+    }
+
+    define_owned!(
+        /// Owned buffer object.
+        Buffer(super::Buffer),
+        |buffer| super::delete_buffers(&[buffer]),
+        "Generates a buffer object.",
+        super::gen_buffers(1)[0]
+    );
+
+    define_owned!(
+        /// Owned texture object.
+        Texture(super::Texture),
+        |texture| super::delete_textures(&[texture]),
+        "Generates a texture object.",
+        super::gen_textures(1)[0]
+    );
+
+    define_owned!(
+        /// Owned vertex array object.
+        VertexArray(super::VertexArray),
+        |array| super::delete_vertex_arrays(&[array]),
+        "Generates a vertex array object.",
+        super::gen_vertex_arrays(1)[0]
+    );
+
+    define_owned!(
+        /// Owned program object.
+        Program(super::Program),
+        super::delete_program,
+        "Creates a program object.",
+        super::create_program()
+    );
+
+    define_owned!(
+        /// Owned framebuffer object.
+        Framebuffer(super::Framebuffer),
+        |framebuffer| super::delete_framebuffers(&[framebuffer]),
+        "Generates a framebuffer object.",
+        super::gen_framebuffers(1)[0]
+    );
+
+    define_owned!(
+        /// Owned renderbuffer object.
+        Renderbuffer(super::Renderbuffer),
+        |renderbuffer| super::delete_renderbuffers(&[renderbuffer]),
+        "Generates a renderbuffer object.",
+        super::gen_renderbuffers(1)[0]
+    );
+
+    define_owned!(
+        /// Owned shader object.
+        Shader(super::Shader),
+        super::delete_shader
+    );
+
+    impl Shader {
+        /// Creates a shader object of the specified type.
+        pub fn new(typ: u32) -> Shader {
+            Shader(super::create_shader(typ))
+        }
+    }
+}
@@ -1,21 +1,38 @@
 //! GLFW bindings.
+//!
+//! Callback setters accept any `FnMut` closure, so callbacks can
+//! capture application state. To unset a callback, pass `None` with the
+//! corresponding callback type (e.g. `None::<Box<FnChar>>`).
 
 use std::{
+    any::Any,
     collections::HashMap,
     error,
-    ffi::{c_char, c_int, c_void, CStr, CString, NulError},
-    fmt, ptr, result,
-    sync::{LazyLock, Mutex},
+    ffi::{c_char, c_double, c_int, c_uint, c_void, CStr, CString, NulError},
+    fmt,
+    path::PathBuf,
+    ptr, result, slice,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        LazyLock, Mutex,
+    },
 };
 
-use crate::macros::{define_enum, define_opaque};
+use crate::{
+    macros::{define_enum, define_opaque},
+    stb_image::Image,
+};
 
 #[allow(non_snake_case)]
 mod ffi {
-    use std::ffi::{c_char, c_double, c_int, c_void};
+    use std::ffi::{c_char, c_double, c_float, c_int, c_void};
+
+    use super::{GlfwGammaRamp, GlfwImage, VideoMode};
 
     #[link(name = "glfw")]
     extern "C" {
+        pub fn glfwCreateCursor(image: *const GlfwImage, xhot: c_int, yhot: c_int) -> *mut c_void;
+        pub fn glfwCreateStandardCursor(shape: c_int) -> *mut c_void;
         pub fn glfwCreateWindow(
             width: c_int,
             height: c_int,
@@ -23,34 +40,165 @@ mod ffi {
             monitor: *mut c_void,
             share: *mut c_void,
         ) -> *mut c_void;
+        pub fn glfwCreateWindowSurface(
+            instance: *mut c_void,
+            window: *mut c_void,
+            allocator: *const c_void,
+            surface: *mut u64,
+        ) -> c_int;
+        pub fn glfwDefaultWindowHints();
+        pub fn glfwDestroyCursor(cursor: *mut c_void);
+        pub fn glfwDestroyWindow(window: *mut c_void);
+        pub fn glfwFocusWindow(window: *mut c_void);
+        pub fn glfwGetClipboardString(window: *mut c_void) -> *const c_char;
+        pub fn glfwGetCurrentContext() -> *mut c_void;
+        pub fn glfwGetCursorPos(window: *mut c_void, xpos: *mut c_double, ypos: *mut c_double);
+        pub fn glfwGetError(description: *mut *const c_char) -> c_int;
+        pub fn glfwGetFramebufferSize(window: *mut c_void, width: *mut c_int, height: *mut c_int);
+        pub fn glfwGetGammaRamp(monitor: *mut c_void) -> *const GlfwGammaRamp;
+        pub fn glfwGetJoystickAxes(jid: c_int, count: *mut c_int) -> *const c_float;
+        pub fn glfwGetJoystickButtons(jid: c_int, count: *mut c_int) -> *const u8;
+        pub fn glfwGetJoystickHats(jid: c_int, count: *mut c_int) -> *const u8;
+        pub fn glfwGetJoystickName(jid: c_int) -> *const c_char;
+        pub fn glfwGetKey(window: *mut c_void, key: c_int) -> c_int;
+        pub fn glfwGetKeyName(key: c_int, scancode: c_int) -> *const c_char;
+        pub fn glfwGetKeyScancode(key: c_int) -> c_int;
+        pub fn glfwGetMonitorContentScale(
+            monitor: *mut c_void,
+            xscale: *mut c_float,
+            yscale: *mut c_float,
+        );
+        pub fn glfwGetMonitorName(monitor: *mut c_void) -> *const c_char;
+        pub fn glfwGetMonitorPhysicalSize(
+            monitor: *mut c_void,
+            width_mm: *mut c_int,
+            height_mm: *mut c_int,
+        );
+        pub fn glfwGetMonitors(count: *mut c_int) -> *mut *mut c_void;
+        pub fn glfwGetMouseButton(window: *mut c_void, button: c_int) -> c_int;
+        pub fn glfwGetPrimaryMonitor() -> *mut c_void;
         pub fn glfwGetProcAddress(procname: *const c_char) -> *const c_void;
+        pub fn glfwGetRequiredInstanceExtensions(count: *mut u32) -> *const *const c_char;
         pub fn glfwGetTime() -> c_double;
+        pub fn glfwGetTimerFrequency() -> u64;
+        pub fn glfwGetTimerValue() -> u64;
+        pub fn glfwGetVideoMode(monitor: *mut c_void) -> *const VideoMode;
+        pub fn glfwGetVideoModes(monitor: *mut c_void, count: *mut c_int) -> *const VideoMode;
+        pub fn glfwGetWindowAttrib(window: *mut c_void, attrib: c_int) -> c_int;
+        pub fn glfwGetWindowContentScale(
+            window: *mut c_void,
+            xscale: *mut c_float,
+            yscale: *mut c_float,
+        );
+        pub fn glfwGetWindowMonitor(window: *mut c_void) -> *mut c_void;
+        pub fn glfwGetWindowPos(window: *mut c_void, xpos: *mut c_int, ypos: *mut c_int);
+        pub fn glfwGetWindowSize(window: *mut c_void, width: *mut c_int, height: *mut c_int);
+        pub fn glfwGetWindowUserPointer(window: *mut c_void) -> *mut c_void;
+        pub fn glfwHideWindow(window: *mut c_void);
         pub fn glfwInit() -> c_int;
+        pub fn glfwJoystickPresent(jid: c_int) -> c_int;
         pub fn glfwMakeContextCurrent(window: *mut c_void);
         pub fn glfwPollEvents();
+        pub fn glfwPostEmptyEvent();
+        pub fn glfwRequestWindowAttention(window: *mut c_void);
+        pub fn glfwSetCharCallback(window: *mut c_void, callback: *const c_void) -> *const c_void;
+        pub fn glfwSetCharModsCallback(
+            window: *mut c_void,
+            callback: *const c_void,
+        ) -> *const c_void;
+        pub fn glfwSetClipboardString(window: *mut c_void, string: *const c_char);
+        pub fn glfwSetCursor(window: *mut c_void, cursor: *mut c_void);
+        pub fn glfwSetCursorPosCallback(
+            window: *mut c_void,
+            callback: *const c_void,
+        ) -> *const c_void;
+        pub fn glfwSetDropCallback(window: *mut c_void, callback: *const c_void) -> *const c_void;
         pub fn glfwSetErrorCallback(callback: *const c_void) -> *const c_void;
         pub fn glfwSetFramebufferSizeCallback(
             window: *mut c_void,
             callback: *const c_void,
         ) -> *const c_void;
+        pub fn glfwSetGamma(monitor: *mut c_void, gamma: c_float);
+        pub fn glfwSetGammaRamp(monitor: *mut c_void, ramp: *const GlfwGammaRamp);
+        pub fn glfwSetInputMode(window: *mut c_void, mode: c_int, value: c_int);
+        pub fn glfwSetJoystickCallback(callback: *const c_void) -> *const c_void;
+        pub fn glfwSetKeyCallback(window: *mut c_void, callback: *const c_void) -> *const c_void;
+        pub fn glfwSetMonitorCallback(callback: *const c_void) -> *const c_void;
+        pub fn glfwSetTime(time: c_double);
+        pub fn glfwSetWindowAspectRatio(window: *mut c_void, numer: c_int, denom: c_int);
+        pub fn glfwSetWindowCloseCallback(
+            window: *mut c_void,
+            callback: *const c_void,
+        ) -> *const c_void;
+        pub fn glfwSetWindowFocusCallback(
+            window: *mut c_void,
+            callback: *const c_void,
+        ) -> *const c_void;
+        pub fn glfwSetWindowIcon(window: *mut c_void, count: c_int, images: *const GlfwImage);
+        pub fn glfwSetWindowIconifyCallback(
+            window: *mut c_void,
+            callback: *const c_void,
+        ) -> *const c_void;
+        pub fn glfwSetWindowMaximizeCallback(
+            window: *mut c_void,
+            callback: *const c_void,
+        ) -> *const c_void;
+        pub fn glfwSetWindowMonitor(
+            window: *mut c_void,
+            monitor: *mut c_void,
+            xpos: c_int,
+            ypos: c_int,
+            width: c_int,
+            height: c_int,
+            refresh_rate: c_int,
+        );
+        pub fn glfwSetWindowPos(window: *mut c_void, xpos: c_int, ypos: c_int);
+        pub fn glfwSetWindowShouldClose(window: *mut c_void, value: c_int);
+        pub fn glfwSetWindowSize(window: *mut c_void, width: c_int, height: c_int);
+        pub fn glfwSetWindowSizeCallback(
+            window: *mut c_void,
+            callback: *const c_void,
+        ) -> *const c_void;
+        pub fn glfwSetWindowSizeLimits(
+            window: *mut c_void,
+            minwidth: c_int,
+            minheight: c_int,
+            maxwidth: c_int,
+            maxheight: c_int,
+        );
+        pub fn glfwSetWindowTitle(window: *mut c_void, title: *const c_char);
+        pub fn glfwSetWindowUserPointer(window: *mut c_void, pointer: *mut c_void);
+        pub fn glfwShowWindow(window: *mut c_void);
         pub fn glfwSwapBuffers(window: *mut c_void);
         pub fn glfwTerminate();
+        pub fn glfwVulkanSupported() -> c_int;
+        pub fn glfwWaitEvents();
+        pub fn glfwWaitEventsTimeout(timeout: c_double);
         pub fn glfwWindowHint(hint: c_int, value: c_int);
         pub fn glfwWindowShouldClose(window: *mut c_void) -> c_int;
     }
 }
 
-/// Context client API major version hint and attribute.
-pub const CONTEXT_VERSION_MAJOR: i32 = 0x00022002;
+/// Don't care value for hints and attributes.
+pub const DONT_CARE: i32 = -1;
 
-/// Context client API minor version hint and attribute.
-pub const CONTEXT_VERSION_MINOR: i32 = 0x00022003;
+/// Joystick hat centered state.
+pub const HAT_CENTERED: u8 = 0;
 
-/// OpenGL profile hint and attribute.
-pub const OPENGL_PROFILE: i32 = 0x00022008;
+/// Joystick hat up bit.
+pub const HAT_UP: u8 = 1;
 
-/// Request core OpenGL profile.
-pub const OPENGL_CORE_PROFILE: i32 = 0x00032001;
+/// Joystick hat right bit.
+pub const HAT_RIGHT: u8 = 2;
+
+/// Joystick hat down bit.
+pub const HAT_DOWN: u8 = 4;
+
+/// Joystick hat left bit.
+pub const HAT_LEFT: u8 = 8;
+
+/// Last joystick ID.
+pub const JOYSTICK_LAST: i32 = 15;
 
 /// A specialized result type.
 pub type Result<T> = result::Result<T, Error>;
@@ -58,6 +206,15 @@ pub type Result<T> = result::Result<T, Error>;
 /// GLFW error.
 #[derive(Debug)]
 pub enum Error {
+    /// Error reported by GLFW.
+    Api {
+        /// Error code.
+        code: ErrorCode,
+
+        /// Human-readable description.
+        description: String,
+    },
+
     /// Error when calling `glfwInit`.
     GlfwInit,
 
@@ -67,6 +224,20 @@ pub enum Error {
     /// Error when calling `glfwGetProcAddress`.
     GlfwGetProcAddress,
 
+    /// Error when calling `glfwCreateCursor` or
+    /// `glfwCreateStandardCursor`.
+    GlfwCreateCursor,
+
+    /// Error when calling `glfwGetClipboardString`.
+    GlfwGetClipboardString,
+
+    /// Error when calling `glfwCreateWindowSurface`. It contains the
+    /// returned `VkResult`.
+    GlfwCreateWindowSurface(i32),
+
+    /// The channels of a gamma ramp have different sizes.
+    GammaRampSize,
+
     /// Invalid C string.
     InvalidCString(NulError),
 }
@@ -80,9 +251,16 @@ impl From<NulError> for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Error::Api { description, .. } => write!(f, "{description}"),
             Error::GlfwInit => write!(f, "failed to initialize GLFW"),
             Error::GlfwCreateWindow => write!(f, "failed to create GLFW window"),
             Error::GlfwGetProcAddress => write!(f, "failed to get function address"),
+            Error::GlfwCreateCursor => write!(f, "failed to create GLFW cursor"),
+            Error::GlfwGetClipboardString => write!(f, "failed to get clipboard contents"),
+            Error::GlfwCreateWindowSurface(result) => {
+                write!(f, "failed to create Vulkan surface (VkResult {result})")
+            }
+            Error::GammaRampSize => write!(f, "gamma ramp channels have different sizes"),
             Error::InvalidCString(err) => write!(f, "invalid C string: {err}"),
         }
     }
@@ -93,6 +271,7 @@ impl error::Error for Error {}
 define_opaque! {
     pub opaque Window(mut);
     pub opaque Monitor(mut);
+    pub opaque Cursor(mut);
     pub opaque GlProc(const);
 }
 
@@ -110,12 +289,464 @@ define_enum! {
         FormatUnavailable  => (0x00010009, "The requested format is not supported or available"),
         NoWindowContext    => (0x0001000a, "The specified window does not have an OpenGL or OpenGL ES context"),
     }
+
+    pub enum MonitorEvent(i32, "Monitor configuration events") {
+        Connected    => (0x00040001, "Connected"),
+        Disconnected => (0x00040002, "Disconnected"),
+    }
+
+    pub enum JoystickEvent(i32, "Joystick configuration events") {
+        Connected    => (0x00040001, "Connected"),
+        Disconnected => (0x00040002, "Disconnected"),
+    }
+
+    pub enum CursorShape(i32, "Standard cursor shapes") {
+        Arrow     => (0x00036001, "Regular arrow cursor"),
+        IBeam     => (0x00036002, "Text input I-beam cursor"),
+        Crosshair => (0x00036003, "Crosshair cursor"),
+        Hand      => (0x00036004, "Hand cursor"),
+        HResize   => (0x00036005, "Horizontal resize arrow cursor"),
+        VResize   => (0x00036006, "Vertical resize arrow cursor"),
+    }
+
+    pub enum Action(i32, "Key and mouse button actions") {
+        Release => (0, "Released"),
+        Press   => (1, "Pressed"),
+        Repeat  => (2, "Held down until it repeated"),
+    }
+
+    pub enum Key(i32, "Keyboard keys") {
+        Space        => (32, "Space"),
+        Apostrophe   => (39, "Apostrophe"),
+        Comma        => (44, "Comma"),
+        Minus        => (45, "Minus"),
+        Period       => (46, "Period"),
+        Slash        => (47, "Slash"),
+        Num0         => (48, "0"),
+        Num1         => (49, "1"),
+        Num2         => (50, "2"),
+        Num3         => (51, "3"),
+        Num4         => (52, "4"),
+        Num5         => (53, "5"),
+        Num6         => (54, "6"),
+        Num7         => (55, "7"),
+        Num8         => (56, "8"),
+        Num9         => (57, "9"),
+        Semicolon    => (59, "Semicolon"),
+        Equal        => (61, "Equal"),
+        A            => (65, "A"),
+        B            => (66, "B"),
+        C            => (67, "C"),
+        D            => (68, "D"),
+        E            => (69, "E"),
+        F            => (70, "F"),
+        G            => (71, "G"),
+        H            => (72, "H"),
+        I            => (73, "I"),
+        J            => (74, "J"),
+        K            => (75, "K"),
+        L            => (76, "L"),
+        M            => (77, "M"),
+        N            => (78, "N"),
+        O            => (79, "O"),
+        P            => (80, "P"),
+        Q            => (81, "Q"),
+        R            => (82, "R"),
+        S            => (83, "S"),
+        T            => (84, "T"),
+        U            => (85, "U"),
+        V            => (86, "V"),
+        W            => (87, "W"),
+        X            => (88, "X"),
+        Y            => (89, "Y"),
+        Z            => (90, "Z"),
+        LeftBracket  => (91, "Left bracket"),
+        Backslash    => (92, "Backslash"),
+        RightBracket => (93, "Right bracket"),
+        GraveAccent  => (96, "Grave accent"),
+        World1       => (161, "Non-US #1"),
+        World2       => (162, "Non-US #2"),
+        Escape       => (256, "Escape"),
+        Enter        => (257, "Enter"),
+        Tab          => (258, "Tab"),
+        Backspace    => (259, "Backspace"),
+        Insert       => (260, "Insert"),
+        Delete       => (261, "Delete"),
+        Right        => (262, "Right"),
+        Left         => (263, "Left"),
+        Down         => (264, "Down"),
+        Up           => (265, "Up"),
+        PageUp       => (266, "Page up"),
+        PageDown     => (267, "Page down"),
+        Home         => (268, "Home"),
+        End          => (269, "End"),
+        CapsLock     => (280, "Caps lock"),
+        ScrollLock   => (281, "Scroll lock"),
+        NumLock      => (282, "Num lock"),
+        PrintScreen  => (283, "Print screen"),
+        Pause        => (284, "Pause"),
+        F1           => (290, "F1"),
+        F2           => (291, "F2"),
+        F3           => (292, "F3"),
+        F4           => (293, "F4"),
+        F5           => (294, "F5"),
+        F6           => (295, "F6"),
+        F7           => (296, "F7"),
+        F8           => (297, "F8"),
+        F9           => (298, "F9"),
+        F10          => (299, "F10"),
+        F11          => (300, "F11"),
+        F12          => (301, "F12"),
+        F13          => (302, "F13"),
+        F14          => (303, "F14"),
+        F15          => (304, "F15"),
+        F16          => (305, "F16"),
+        F17          => (306, "F17"),
+        F18          => (307, "F18"),
+        F19          => (308, "F19"),
+        F20          => (309, "F20"),
+        F21          => (310, "F21"),
+        F22          => (311, "F22"),
+        F23          => (312, "F23"),
+        F24          => (313, "F24"),
+        F25          => (314, "F25"),
+        Kp0          => (320, "Keypad 0"),
+        Kp1          => (321, "Keypad 1"),
+        Kp2          => (322, "Keypad 2"),
+        Kp3          => (323, "Keypad 3"),
+        Kp4          => (324, "Keypad 4"),
+        Kp5          => (325, "Keypad 5"),
+        Kp6          => (326, "Keypad 6"),
+        Kp7          => (327, "Keypad 7"),
+        Kp8          => (328, "Keypad 8"),
+        Kp9          => (329, "Keypad 9"),
+        KpDecimal    => (330, "Keypad decimal"),
+        KpDivide     => (331, "Keypad divide"),
+        KpMultiply   => (332, "Keypad multiply"),
+        KpSubtract   => (333, "Keypad subtract"),
+        KpAdd        => (334, "Keypad add"),
+        KpEnter      => (335, "Keypad enter"),
+        KpEqual      => (336, "Keypad equal"),
+        LeftShift    => (340, "Left shift"),
+        LeftControl  => (341, "Left control"),
+        LeftAlt      => (342, "Left alt"),
+        LeftSuper    => (343, "Left super"),
+        RightShift   => (344, "Right shift"),
+        RightControl => (345, "Right control"),
+        RightAlt     => (346, "Right alt"),
+        RightSuper   => (347, "Right super"),
+        Menu         => (348, "Menu"),
+    }
+
+    pub enum MouseButton(i32, "Mouse buttons") {
+        Left    => (0, "Left"),
+        Right   => (1, "Right"),
+        Middle  => (2, "Middle"),
+        Button4 => (3, "Button 4"),
+        Button5 => (4, "Button 5"),
+        Button6 => (5, "Button 6"),
+        Button7 => (6, "Button 7"),
+        Button8 => (7, "Button 8"),
+    }
+
+    pub enum WindowAttrib(i32, "Window and context attributes") {
+        Focused                => (0x00020001, "Whether the window has input focus"),
+        Iconified              => (0x00020002, "Whether the window is iconified"),
+        Resizable              => (0x00020003, "Whether the window is resizable by the user"),
+        Visible                => (0x00020004, "Whether the window is visible"),
+        Decorated              => (0x00020005, "Whether the window has decorations"),
+        Floating               => (0x00020007, "Whether the window is floating"),
+        Maximized              => (0x00020008, "Whether the window is maximized"),
+        TransparentFramebuffer => (0x0002000a, "Whether the window framebuffer is transparent"),
+        Hovered                => (0x0002000b, "Whether the cursor is over the content area of the window"),
+        ContextVersionMajor    => (0x00022002, "Client API major version of the context"),
+        ContextVersionMinor    => (0x00022003, "Client API minor version of the context"),
+        ContextRevision        => (0x00022004, "Client API revision of the context"),
+        OpenGlForwardCompat    => (0x00022006, "Whether the context is forward-compatible"),
+        OpenGlDebugContext     => (0x00022007, "Whether the context is in debug mode"),
+        OpenGlProfile          => (0x00022008, "OpenGL profile of the context"),
+    }
+}
+
+/// Video mode of a monitor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct VideoMode {
+    /// Width, in screen coordinates.
+    pub width: i32,
+
+    /// Height, in screen coordinates.
+    pub height: i32,
+
+    /// Bit depth of the red channel.
+    pub red_bits: i32,
+
+    /// Bit depth of the green channel.
+    pub green_bits: i32,
+
+    /// Bit depth of the blue channel.
+    pub blue_bits: i32,
+
+    /// Refresh rate, in Hz.
+    pub refresh_rate: i32,
+}
+
+/// Client API to create the context for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientApi {
+    /// OpenGL.
+    OpenGl,
+
+    /// OpenGL ES.
+    OpenGlEs,
+
+    /// No context, e.g. for Vulkan rendering.
+    NoApi,
+}
+
+/// OpenGL profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenGlProfile {
+    /// Any profile, or unknown for versions below 3.2.
+    Any,
+
+    /// Core profile.
+    Core,
+
+    /// Compatibility profile.
+    Compat,
+}
+
+/// Window creation hint. Hints that take a bit depth, sample count or
+/// refresh rate accept `None` to mean "don't care".
+///
+/// A borderless window covering a monitor can be created by setting
+/// `Decorated(false)` and `Floating(true)` and passing the size of the
+/// monitor video mode to [`create_window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowHint {
+    /// Whether the windowed mode window will be given input focus when
+    /// created.
+    Focused(bool),
+
+    /// Whether the windowed mode window will be resizable by the user.
+    Resizable(bool),
+
+    /// Whether the windowed mode window will be initially visible.
+    Visible(bool),
+
+    /// Whether the windowed mode window will have window decorations.
+    Decorated(bool),
+
+    /// Whether the full screen window will automatically iconify and
+    /// restore the previous video mode on input focus loss.
+    AutoIconify(bool),
+
+    /// Whether the windowed mode window will be floating above other
+    /// regular windows.
+    Floating(bool),
+
+    /// Whether the windowed mode window will be maximized when
+    /// created.
+    Maximized(bool),
+
+    /// Whether the cursor should be centered over newly created full
+    /// screen windows.
+    CenterCursor(bool),
+
+    /// Whether the window framebuffer will be transparent.
+    TransparentFramebuffer(bool),
+
+    /// Desired bit depth of the red channel of the default
+    /// framebuffer.
+    RedBits(Option<i32>),
+
+    /// Desired bit depth of the green channel of the default
+    /// framebuffer.
+    GreenBits(Option<i32>),
+
+    /// Desired bit depth of the blue channel of the default
+    /// framebuffer.
+    BlueBits(Option<i32>),
+
+    /// Desired bit depth of the alpha channel of the default
+    /// framebuffer.
+    AlphaBits(Option<i32>),
+
+    /// Desired bit depth of the depth buffer of the default
+    /// framebuffer.
+    DepthBits(Option<i32>),
+
+    /// Desired bit depth of the stencil buffer of the default
+    /// framebuffer.
+    StencilBits(Option<i32>),
+
+    /// Desired number of samples to use for multisampling.
+    Samples(Option<i32>),
+
+    /// Whether the framebuffer should be sRGB capable.
+    SrgbCapable(bool),
+
+    /// Desired refresh rate for full screen windows.
+    RefreshRate(Option<i32>),
+
+    /// Whether the framebuffer should be double buffered.
+    DoubleBuffer(bool),
+
+    /// Client API to create the context for.
+    ClientApi(ClientApi),
+
+    /// Client API major version that the created context must be
+    /// compatible with.
+    ContextVersionMajor(i32),
+
+    /// Client API minor version that the created context must be
+    /// compatible with.
+    ContextVersionMinor(i32),
+
+    /// Whether the OpenGL context should be forward-compatible.
+    OpenGlForwardCompat(bool),
+
+    /// Whether the context should be created in debug mode.
+    OpenGlDebugContext(bool),
+
+    /// OpenGL profile to create the context for.
+    OpenGlProfile(OpenGlProfile),
+
+    /// Whether the window content area should be resized based on the
+    /// monitor content scale.
+    ScaleToMonitor(bool),
+}
+
+impl WindowHint {
+    /// Returns the GLFW hint and value.
+    fn hint_value(self) -> (c_int, c_int) {
+        let bits = |v: Option<i32>| v.unwrap_or(DONT_CARE);
+        match self {
+            WindowHint::Focused(v) => (0x00020001, v as c_int),
+            WindowHint::Resizable(v) => (0x00020003, v as c_int),
+            WindowHint::Visible(v) => (0x00020004, v as c_int),
+            WindowHint::Decorated(v) => (0x00020005, v as c_int),
+            WindowHint::AutoIconify(v) => (0x00020006, v as c_int),
+            WindowHint::Floating(v) => (0x00020007, v as c_int),
+            WindowHint::Maximized(v) => (0x00020008, v as c_int),
+            WindowHint::CenterCursor(v) => (0x00020009, v as c_int),
+            WindowHint::TransparentFramebuffer(v) => (0x0002000a, v as c_int),
+            WindowHint::RedBits(v) => (0x00021001, bits(v)),
+            WindowHint::GreenBits(v) => (0x00021002, bits(v)),
+            WindowHint::BlueBits(v) => (0x00021003, bits(v)),
+            WindowHint::AlphaBits(v) => (0x00021004, bits(v)),
+            WindowHint::DepthBits(v) => (0x00021005, bits(v)),
+            WindowHint::StencilBits(v) => (0x00021006, bits(v)),
+            WindowHint::Samples(v) => (0x0002100d, bits(v)),
+            WindowHint::SrgbCapable(v) => (0x0002100e, v as c_int),
+            WindowHint::RefreshRate(v) => (0x0002100f, bits(v)),
+            WindowHint::DoubleBuffer(v) => (0x00021010, v as c_int),
+            WindowHint::ClientApi(v) => {
+                let api = match v {
+                    ClientApi::OpenGl => 0x00030001,
+                    ClientApi::OpenGlEs => 0x00030002,
+                    ClientApi::NoApi => 0,
+                };
+                (0x00022001, api)
+            }
+            WindowHint::ContextVersionMajor(v) => (0x00022002, v),
+            WindowHint::ContextVersionMinor(v) => (0x00022003, v),
+            WindowHint::OpenGlForwardCompat(v) => (0x00022006, v as c_int),
+            WindowHint::OpenGlDebugContext(v) => (0x00022007, v as c_int),
+            WindowHint::OpenGlProfile(v) => {
+                let profile = match v {
+                    OpenGlProfile::Any => 0,
+                    OpenGlProfile::Core => 0x00032001,
+                    OpenGlProfile::Compat => 0x00032002,
+                };
+                (0x00022008, profile)
+            }
+            WindowHint::ScaleToMonitor(v) => (0x0002200c, v as c_int),
+        }
+    }
+}
+
+/// Input mode of a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    /// Whether key presses are remembered until polled with
+    /// [`get_key`], even if the key has been released in the meantime.
+    StickyKeys(bool),
+
+    /// Whether mouse button presses are remembered until polled with
+    /// [`get_mouse_button`], even if the button has been released in
+    /// the meantime.
+    StickyMouseButtons(bool),
+
+    /// Whether the modifier bits passed to callbacks include the state
+    /// of Caps Lock and Num Lock.
+    LockKeyMods(bool),
+}
+
+impl InputMode {
+    /// Returns the GLFW input mode and value.
+    fn mode_value(self) -> (c_int, c_int) {
+        match self {
+            InputMode::StickyKeys(v) => (0x00033002, v as c_int),
+            InputMode::StickyMouseButtons(v) => (0x00033003, v as c_int),
+            InputMode::LockKeyMods(v) => (0x00033004, v as c_int),
+        }
+    }
+}
+
+/// Image data passed to GLFW.
+#[repr(C)]
+struct GlfwImage {
+    width: c_int,
+    height: c_int,
+    pixels: *const u8,
+}
+
+/// Gamma ramp of a monitor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GammaRamp {
+    /// Responses of the red channel.
+    pub red: Vec<u16>,
+
+    /// Responses of the green channel.
+    pub green: Vec<u16>,
+
+    /// Responses of the blue channel.
+    pub blue: Vec<u16>,
+}
+
+/// Gamma ramp data passed to GLFW.
+#[repr(C)]
+struct GlfwGammaRamp {
+    red: *const u16,
+    green: *const u16,
+    blue: *const u16,
+    size: c_uint,
+}
+
+/// Returns the pixels of the provided image as 8-bit RGBA, which is
+/// the format expected by GLFW.
+fn rgba_pixels(image: &Image) -> Vec<u8> {
+    let pixels = image.pixels();
+    match image.channels() {
+        1 => pixels.iter().flat_map(|&l| [l, l, l, 255]).collect(),
+        2 => pixels
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        3 => pixels
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        _ => pixels.to_vec(),
+    }
 }
 
 /// Initializes the GLFW library.
 pub fn init() -> Result<()> {
     if unsafe { ffi::glfwInit() == 0 } {
-        return Err(Error::GlfwInit);
+        return Err(last_error_or(Error::GlfwInit));
     }
     Ok(())
 }
@@ -138,28 +769,423 @@ pub fn create_window(
     let share = share.map_or(ptr::null_mut(), |w| w.as_mut_ptr());
     let window = unsafe { ffi::glfwCreateWindow(width, height, title.as_ptr(), monitor, share) };
     if window.is_null() {
-        return Err(Error::GlfwCreateWindow);
+        return Err(last_error_or(Error::GlfwCreateWindow));
     }
     Ok(Window(window))
 }
 
+/// Creates a cursor with the provided image. The hotspot is specified
+/// in pixels, relative to the upper-left corner of the image.
+pub fn create_cursor(image: &Image, xhot: i32, yhot: i32) -> Result<Cursor> {
+    let pixels = rgba_pixels(image);
+    let image = GlfwImage {
+        width: image.width() as c_int,
+        height: image.height() as c_int,
+        pixels: pixels.as_ptr(),
+    };
+    let cursor = unsafe { ffi::glfwCreateCursor(&image, xhot, yhot) };
+    if cursor.is_null() {
+        return Err(last_error_or(Error::GlfwCreateCursor));
+    }
+    Ok(Cursor(cursor))
+}
+
+/// Creates a cursor with a standard shape.
+pub fn create_standard_cursor(shape: CursorShape) -> Result<Cursor> {
+    let cursor = unsafe { ffi::glfwCreateStandardCursor(shape.into()) };
+    if cursor.is_null() {
+        return Err(last_error_or(Error::GlfwCreateCursor));
+    }
+    Ok(Cursor(cursor))
+}
+
+/// Creates a Vulkan surface for the specified window. `instance` is
+/// the `VkInstance` handle. The window must have been created with
+/// the [`WindowHint::ClientApi`] hint set to [`ClientApi::NoApi`]. On
+/// success, it returns the `VkSurfaceKHR` handle.
+///
+/// # Safety
+///
+/// `instance` must be a valid `VkInstance` created with the extensions
+/// returned by [`get_required_instance_extensions`].
+pub unsafe fn create_window_surface(instance: *mut c_void, window: Window) -> Result<u64> {
+    let mut surface = 0;
+    let result =
+        ffi::glfwCreateWindowSurface(instance, window.as_mut_ptr(), ptr::null(), &mut surface);
+    if result != 0 {
+        return Err(Error::GlfwCreateWindowSurface(result));
+    }
+    Ok(surface)
+}
+
+/// Resets all window hints to their default values.
+pub fn default_window_hints() {
+    unsafe { ffi::glfwDefaultWindowHints() }
+}
+
+/// Destroys the specified cursor. If it is set for any window, those
+/// windows revert to the default cursor.
+pub fn destroy_cursor(cursor: Cursor) {
+    unsafe { ffi::glfwDestroyCursor(cursor.as_mut_ptr()) }
+}
+
+/// Destroys the specified window and its context, unregistering its
+/// callbacks and dropping its user data.
+pub fn destroy_window(window: Window) {
+    for callbacks in [
+        &*CHAR_CALLBACKS as &dyn Unregister,
+        &*CHAR_MODS_CALLBACKS,
+        &*CURSOR_POS_CALLBACKS,
+        &*DROP_CALLBACKS,
+        &*FRAMEBUFFER_SIZE_CALLBACKS,
+        &*KEY_CALLBACKS,
+        &*WINDOW_CLOSE_CALLBACKS,
+        &*WINDOW_FOCUS_CALLBACKS,
+        &*WINDOW_ICONIFY_CALLBACKS,
+        &*WINDOW_MAXIMIZE_CALLBACKS,
+        &*WINDOW_SIZE_CALLBACKS,
+    ] {
+        callbacks.unregister(window);
+    }
+    drop(take_user_data(window));
+    WINDOWED_GEOMETRY.lock().unwrap().remove(&window);
+    unsafe { ffi::glfwDestroyWindow(window.as_mut_ptr()) }
+}
+
+/// Brings the specified window to front and sets input focus. The
+/// window should already be visible and not iconified.
+pub fn focus_window(window: Window) {
+    unsafe { ffi::glfwFocusWindow(window.as_mut_ptr()) }
+}
+
+/// Returns the contents of the system clipboard, if it contains or is
+/// convertible to a UTF-8 encoded string.
+pub fn get_clipboard_string(window: Window) -> Result<String> {
+    let string = unsafe { ffi::glfwGetClipboardString(window.as_mut_ptr()) };
+    if string.is_null() {
+        return Err(last_error_or(Error::GlfwGetClipboardString));
+    }
+    let string = unsafe { CStr::from_ptr(string) };
+    Ok(string.to_string_lossy().into_owned())
+}
+
+/// Returns the window whose context is current on the calling thread.
+pub fn get_current_context() -> Option<Window> {
+    let window = unsafe { ffi::glfwGetCurrentContext() };
+    if window.is_null() {
+        return None;
+    }
+    Some(Window(window))
+}
+
+/// Returns the position of the cursor, in screen coordinates,
+/// relative to the upper-left corner of the content area of the
+/// specified window.
+pub fn get_cursor_pos(window: Window) -> (f64, f64) {
+    let (mut x, mut y) = (0.0, 0.0);
+    unsafe { ffi::glfwGetCursorPos(window.as_mut_ptr(), &mut x, &mut y) };
+    (x, y)
+}
+
+/// Returns and clears the last error that occurred on the calling
+/// thread. If no error has occurred since the last call, it returns
+/// [`ErrorCode::NoError`] and an empty description.
+pub fn get_error() -> (ErrorCode, String) {
+    let mut description = ptr::null();
+    let code = unsafe { ffi::glfwGetError(&mut description) };
+    let description = if description.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(description) }
+            .to_string_lossy()
+            .into_owned()
+    };
+    (code.into(), description)
+}
+
+/// Returns the last GLFW error as an [`Error::Api`] or, if there is
+/// none, `fallback`.
+fn last_error_or(fallback: Error) -> Error {
+    match get_error() {
+        (ErrorCode::NoError, _) => fallback,
+        (code, description) => Error::Api { code, description },
+    }
+}
+
+/// Returns the size, in pixels, of the framebuffer of the specified
+/// window. It may differ from the window size on HiDPI displays.
+pub fn get_framebuffer_size(window: Window) -> (i32, i32) {
+    let (mut width, mut height) = (0, 0);
+    unsafe { ffi::glfwGetFramebufferSize(window.as_mut_ptr(), &mut width, &mut height) };
+    (width, height)
+}
+
+/// Returns the current gamma ramp of the specified monitor.
+pub fn get_gamma_ramp(monitor: Monitor) -> Option<GammaRamp> {
+    let ramp = unsafe { ffi::glfwGetGammaRamp(monitor.as_mut_ptr()) };
+    if ramp.is_null() {
+        return None;
+    }
+    let ramp = unsafe { &*ramp };
+    let channel = |ptr| unsafe { slice::from_raw_parts(ptr, ramp.size as usize) }.to_vec();
+    Some(GammaRamp {
+        red: channel(ramp.red),
+        green: channel(ramp.green),
+        blue: channel(ramp.blue),
+    })
+}
+
+/// Returns the values of all axes of the specified joystick, in the
+/// `[-1, 1]` range. It returns an empty vector if the joystick is not
+/// present.
+pub fn get_joystick_axes(jid: i32) -> Vec<f32> {
+    let mut count = 0;
+    let axes = unsafe { ffi::glfwGetJoystickAxes(jid, &mut count) };
+    if axes.is_null() {
+        return Vec::new();
+    }
+    unsafe { slice::from_raw_parts(axes, count as usize) }.to_vec()
+}
+
+/// Returns the state of all buttons of the specified joystick. It
+/// returns an empty vector if the joystick is not present.
+pub fn get_joystick_buttons(jid: i32) -> Vec<Action> {
+    let mut count = 0;
+    let buttons = unsafe { ffi::glfwGetJoystickButtons(jid, &mut count) };
+    if buttons.is_null() {
+        return Vec::new();
+    }
+    unsafe { slice::from_raw_parts(buttons, count as usize) }
+        .iter()
+        .map(|&b| Action::from(b as i32))
+        .collect()
+}
+
+/// Returns the state of all hats of the specified joystick, as a
+/// combination of the `HAT_*` bits. It returns an empty vector if the
+/// joystick is not present.
+pub fn get_joystick_hats(jid: i32) -> Vec<u8> {
+    let mut count = 0;
+    let hats = unsafe { ffi::glfwGetJoystickHats(jid, &mut count) };
+    if hats.is_null() {
+        return Vec::new();
+    }
+    unsafe { slice::from_raw_parts(hats, count as usize) }.to_vec()
+}
+
+/// Returns the name of the specified joystick, or `None` if it is not
+/// present.
+pub fn get_joystick_name(jid: i32) -> Option<String> {
+    let name = unsafe { ffi::glfwGetJoystickName(jid) };
+    if name.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(name) };
+    Some(name.to_string_lossy().into_owned())
+}
+
+/// Returns the last state reported for the specified key to the
+/// specified window. It is either [`Action::Press`] or
+/// [`Action::Release`].
+pub fn get_key(window: Window, key: Key) -> Action {
+    unsafe { ffi::glfwGetKey(window.as_mut_ptr(), key.into()) }.into()
+}
+
+/// Returns the layout-specific name of the specified printable key.
+/// If `key` is [`Key::Unknown`], the key is identified by `scancode`
+/// instead.
+pub fn get_key_name(key: Key, scancode: i32) -> Option<String> {
+    let name = unsafe { ffi::glfwGetKeyName(key.into(), scancode) };
+    if name.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(name) };
+    Some(name.to_string_lossy().into_owned())
+}
+
+/// Returns the platform-specific scancode of the specified key.
+pub fn get_key_scancode(key: Key) -> Option<i32> {
+    let scancode = unsafe { ffi::glfwGetKeyScancode(key.into()) };
+    if scancode == -1 {
+        return None;
+    }
+    Some(scancode)
+}
+
+/// Returns the content scale of the specified monitor. It is the
+/// ratio between the current DPI and the platform's default DPI.
+pub fn get_monitor_content_scale(monitor: Monitor) -> (f32, f32) {
+    let (mut xscale, mut yscale) = (0.0, 0.0);
+    unsafe { ffi::glfwGetMonitorContentScale(monitor.as_mut_ptr(), &mut xscale, &mut yscale) };
+    (xscale, yscale)
+}
+
+/// Returns the human-readable name of the specified monitor.
+pub fn get_monitor_name(monitor: Monitor) -> Option<String> {
+    let name = unsafe { ffi::glfwGetMonitorName(monitor.as_mut_ptr()) };
+    if name.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(name) };
+    Some(name.to_string_lossy().into_owned())
+}
+
+/// Returns the size, in millimetres, of the display area of the
+/// specified monitor.
+pub fn get_monitor_physical_size(monitor: Monitor) -> (i32, i32) {
+    let (mut width_mm, mut height_mm) = (0, 0);
+    unsafe { ffi::glfwGetMonitorPhysicalSize(monitor.as_mut_ptr(), &mut width_mm, &mut height_mm) };
+    (width_mm, height_mm)
+}
+
+/// Returns the currently connected monitors. The primary monitor is
+/// always first.
+pub fn get_monitors() -> Vec<Monitor> {
+    let mut count = 0;
+    let monitors = unsafe { ffi::glfwGetMonitors(&mut count) };
+    if monitors.is_null() {
+        return Vec::new();
+    }
+    unsafe { slice::from_raw_parts(monitors, count as usize) }
+        .iter()
+        .map(|&m| Monitor(m))
+        .collect()
+}
+
+/// Returns the last state reported for the specified mouse button to
+/// the specified window. It is either [`Action::Press`] or
+/// [`Action::Release`].
+pub fn get_mouse_button(window: Window, button: MouseButton) -> Action {
+    unsafe { ffi::glfwGetMouseButton(window.as_mut_ptr(), button.into()) }.into()
+}
+
+/// Returns the primary monitor, usually the one where the taskbar or
+/// global menu bar is located.
+pub fn get_primary_monitor() -> Option<Monitor> {
+    let monitor = unsafe { ffi::glfwGetPrimaryMonitor() };
+    if monitor.is_null() {
+        return None;
+    }
+    Some(Monitor(monitor))
+}
+
 /// Returns the address of the specified function for the current
 /// context.
 pub fn get_proc_address(procname: &str) -> Result<GlProc> {
     let procname = CString::new(procname)?;
     let proc = unsafe { ffi::glfwGetProcAddress(procname.as_ptr()) };
     if proc.is_null() {
-        return Err(Error::GlfwGetProcAddress);
+        return Err(last_error_or(Error::GlfwGetProcAddress));
     }
     Ok(GlProc(proc))
 }
 
+/// Returns the Vulkan instance extensions required by GLFW to create
+/// window surfaces. It returns an empty vector if Vulkan is not
+/// available.
+pub fn get_required_instance_extensions() -> Vec<String> {
+    let mut count = 0;
+    let extensions = unsafe { ffi::glfwGetRequiredInstanceExtensions(&mut count) };
+    if extensions.is_null() {
+        return Vec::new();
+    }
+    unsafe { slice::from_raw_parts(extensions, count as usize) }
+        .iter()
+        .map(|&ext| {
+            unsafe { CStr::from_ptr(ext) }
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect()
+}
+
 /// Returns the value of the GLFW timer. Unless the timer has been
 /// set, the timer measures time elapsed since GLFW was initialized.
 pub fn get_time() -> f64 {
     unsafe { ffi::glfwGetTime() }
 }
 
+/// Returns the frequency, in Hz, of the raw timer.
+pub fn get_timer_frequency() -> u64 {
+    unsafe { ffi::glfwGetTimerFrequency() }
+}
+
+/// Returns the current value of the raw timer, measured in
+/// 1 / [`get_timer_frequency`] seconds.
+pub fn get_timer_value() -> u64 {
+    unsafe { ffi::glfwGetTimerValue() }
+}
+
+/// Returns the current video mode of the specified monitor.
+pub fn get_video_mode(monitor: Monitor) -> Option<VideoMode> {
+    let mode = unsafe { ffi::glfwGetVideoMode(monitor.as_mut_ptr()) };
+    if mode.is_null() {
+        return None;
+    }
+    Some(unsafe { *mode })
+}
+
+/// Returns the video modes supported by the specified monitor, sorted
+/// in ascending order.
+pub fn get_video_modes(monitor: Monitor) -> Vec<VideoMode> {
+    let mut count = 0;
+    let modes = unsafe { ffi::glfwGetVideoModes(monitor.as_mut_ptr(), &mut count) };
+    if modes.is_null() {
+        return Vec::new();
+    }
+    unsafe { slice::from_raw_parts(modes, count as usize) }.to_vec()
+}
+
+/// Returns the value of an attribute of the specified window or its
+/// context. Boolean attributes are returned as 0 or 1.
+pub fn get_window_attrib(window: Window, attrib: WindowAttrib) -> i32 {
+    unsafe { ffi::glfwGetWindowAttrib(window.as_mut_ptr(), attrib.into()) }
+}
+
+/// Returns the content scale of the specified window. It is the ratio
+/// between the current DPI and the platform's default DPI.
+pub fn get_window_content_scale(window: Window) -> (f32, f32) {
+    let (mut xscale, mut yscale) = (0.0, 0.0);
+    unsafe { ffi::glfwGetWindowContentScale(window.as_mut_ptr(), &mut xscale, &mut yscale) };
+    (xscale, yscale)
+}
+
+/// Returns the monitor that the specified window is in full screen
+/// on, or `None` if it is in windowed mode.
+pub fn get_window_monitor(window: Window) -> Option<Monitor> {
+    let monitor = unsafe { ffi::glfwGetWindowMonitor(window.as_mut_ptr()) };
+    if monitor.is_null() {
+        return None;
+    }
+    Some(Monitor(monitor))
+}
+
+/// Returns the position, in screen coordinates, of the upper-left
+/// corner of the content area of the specified window.
+pub fn get_window_pos(window: Window) -> (i32, i32) {
+    let (mut x, mut y) = (0, 0);
+    unsafe { ffi::glfwGetWindowPos(window.as_mut_ptr(), &mut x, &mut y) };
+    (x, y)
+}
+
+/// Returns the size, in screen coordinates, of the content area of
+/// the specified window.
+pub fn get_window_size(window: Window) -> (i32, i32) {
+    let (mut width, mut height) = (0, 0);
+    unsafe { ffi::glfwGetWindowSize(window.as_mut_ptr(), &mut width, &mut height) };
+    (width, height)
+}
+
+/// Hides the specified window, if it was previously visible.
+pub fn hide_window(window: Window) {
+    unsafe { ffi::glfwHideWindow(window.as_mut_ptr()) }
+}
+
+/// Returns whether the specified joystick is present.
+pub fn joystick_present(jid: i32) -> bool {
+    unsafe { ffi::glfwJoystickPresent(jid) != 0 }
+}
+
 /// Makes the context of the specified window current for the calling
 /// thread.
 pub fn make_context_current(window: Window) {
@@ -171,71 +1197,820 @@ pub fn poll_events() {
     unsafe { ffi::glfwPollEvents() }
 }
 
-/// Error callback.
-pub type FnError = fn(error_code: ErrorCode, description: &str);
+/// Posts an empty event to the event queue, causing [`wait_events`]
+/// or [`wait_events_timeout`] to return. It may be called from any
+/// thread.
+pub fn post_empty_event() {
+    unsafe { ffi::glfwPostEmptyEvent() }
+}
 
-static ERROR_CALLBACK: Mutex<Option<FnError>> = Mutex::new(None);
+/// Requests user attention to the specified window. On platforms
+/// where this is not supported, attention is requested to the
+/// application as a whole.
+pub fn request_window_attention(window: Window) {
+    unsafe { ffi::glfwRequestWindowAttention(window.as_mut_ptr()) }
+}
 
-extern "C" fn error_callback(error_code: c_int, description: *const c_char) {
-    let cb = ERROR_CALLBACK
-        .lock()
-        .unwrap()
-        .expect("GLFW error callback is not set");
-    let description = unsafe { CStr::from_ptr(description) }
-        .to_str()
-        .expect("GLFW error description is not a valid UTF-8 string");
-    cb(error_code.into(), description);
+/// Registered callback. `generation` identifies the registration, so
+/// a callback that is running can tell whether it has been replaced
+/// or unregistered in the meantime.
+struct CallbackSlot<T: ?Sized> {
+    callback: Option<Box<T>>,
+    generation: u64,
 }
 
-/// Sets the error callback.
-pub fn set_error_callback(callback: Option<FnError>) {
-    *ERROR_CALLBACK.lock().unwrap() = callback;
-    let cb = if callback.is_some() {
-        error_callback as *const c_void
-    } else {
-        ptr::null()
-    };
-    unsafe { ffi::glfwSetErrorCallback(cb) };
+impl<T: ?Sized> CallbackSlot<T> {
+    /// Returns an empty slot.
+    const fn new() -> CallbackSlot<T> {
+        CallbackSlot {
+            callback: None,
+            generation: 0,
+        }
+    }
+
+    /// Returns a slot holding `callback` with a new generation.
+    fn with_callback(callback: Box<T>) -> CallbackSlot<T> {
+        static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+        CallbackSlot {
+            callback: Some(callback),
+            generation: NEXT_GENERATION.fetch_add(1, Ordering::Relaxed),
+        }
+    }
 }
 
-/// Framebuffer size change callback.
-pub type FnFramebufferSize = fn(window: Window, width: i32, height: i32);
+/// Per-window callback registry.
+type WindowCallbacks<T> = LazyLock<Mutex<HashMap<Window, CallbackSlot<T>>>>;
 
-static FRAMEBUFFER_SIZE_CALLBACKS: LazyLock<Mutex<HashMap<Window, Option<FnFramebufferSize>>>> =
-    LazyLock::new(|| Mutex::new(HashMap::new()));
+/// Global callback slot.
+type GlobalCallback<T> = Mutex<CallbackSlot<T>>;
 
-extern "C" fn framebuffer_size_callback(window: *mut c_void, width: c_int, height: c_int) {
-    let window = Window(window);
-    let cb = FRAMEBUFFER_SIZE_CALLBACKS
-        .lock()
-        .unwrap()
-        .get(&window)
-        .expect("unknown GLFW window")
-        .expect("GLFW framebuffer size callback is not set");
-    cb(window, width, height);
+/// Removal of a window from a callback registry, regardless of the
+/// callback type.
+trait Unregister {
+    fn unregister(&self, window: Window);
 }
 
-/// Sets the framebuffer resize callback for the specified window.
-pub fn set_framebuffer_size_callback(window: Window, callback: Option<FnFramebufferSize>) {
-    FRAMEBUFFER_SIZE_CALLBACKS
-        .lock()
-        .unwrap()
-        .insert(window, callback);
-    let cb = if callback.is_some() {
-        framebuffer_size_callback as *const c_void
-    } else {
-        ptr::null()
+impl<T: ?Sized> Unregister for Mutex<HashMap<Window, CallbackSlot<T>>> {
+    fn unregister(&self, window: Window) {
+        self.lock().unwrap().remove(&window);
+    }
+}
+
+/// Registers or, if `callback` is `None`, unregisters the callback of
+/// the specified window. It returns the trampoline to pass to GLFW,
+/// which is null if the callback has been unregistered.
+fn set_window_callback<T: ?Sized>(
+    callbacks: &Mutex<HashMap<Window, CallbackSlot<T>>>,
+    window: Window,
+    callback: Option<Box<T>>,
+    trampoline: *const c_void,
+) -> *const c_void {
+    let mut callbacks = callbacks.lock().unwrap();
+    match callback {
+        Some(callback) => {
+            callbacks.insert(window, CallbackSlot::with_callback(callback));
+            trampoline
+        }
+        None => {
+            callbacks.remove(&window);
+            ptr::null()
+        }
+    }
+}
+
+/// Calls the callback of the specified window. The callback is taken
+/// out of the registry while it runs, so it can register callbacks
+/// itself without deadlocking. Re-entrant calls, which happen when the
+/// callback triggers its own event synchronously, are ignored. The
+/// callback is only put back if it has not been replaced or
+/// unregistered while running; otherwise, it is dropped.
+fn call_window_callback<T: ?Sized>(
+    callbacks: &Mutex<HashMap<Window, CallbackSlot<T>>>,
+    window: Window,
+    call: impl FnOnce(&mut T),
+) {
+    let (mut cb, generation) = {
+        let mut callbacks = callbacks.lock().unwrap();
+        let Some(slot) = callbacks.get_mut(&window) else {
+            return;
+        };
+        let Some(cb) = slot.callback.take() else {
+            return;
+        };
+        (cb, slot.generation)
+    };
+    call(&mut cb);
+    let mut callbacks = callbacks.lock().unwrap();
+    if let Some(slot) = callbacks.get_mut(&window) {
+        if slot.generation == generation {
+            slot.callback = Some(cb);
+        }
+    }
+}
+
+/// Registers or, if `callback` is `None`, unregisters a global
+/// callback. It returns the trampoline to pass to GLFW, which is null
+/// if the callback has been unregistered.
+fn set_global_callback<T: ?Sized>(
+    slot: &GlobalCallback<T>,
+    callback: Option<Box<T>>,
+    trampoline: *const c_void,
+) -> *const c_void {
+    let (new_slot, cb) = match callback {
+        Some(callback) => (CallbackSlot::with_callback(callback), trampoline),
+        None => (CallbackSlot::new(), ptr::null()),
+    };
+    *slot.lock().unwrap() = new_slot;
+    cb
+}
+
+/// Calls a global callback. Like [`call_window_callback`], the
+/// callback is taken out of its slot while it runs, re-entrant calls
+/// are ignored and the callback is only put back if the slot has not
+/// changed.
+fn call_global_callback<T: ?Sized>(slot: &GlobalCallback<T>, call: impl FnOnce(&mut T)) {
+    let (mut cb, generation) = {
+        let mut slot = slot.lock().unwrap();
+        let Some(cb) = slot.callback.take() else {
+            return;
+        };
+        (cb, slot.generation)
     };
+    call(&mut cb);
+    let mut slot = slot.lock().unwrap();
+    if slot.generation == generation {
+        slot.callback = Some(cb);
+    }
+}
+
+/// Unicode character callback.
+pub type FnChar = dyn FnMut(Window, char) + Send;
+
+static CHAR_CALLBACKS: WindowCallbacks<FnChar> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn char_callback(window: *mut c_void, codepoint: c_uint) {
+    let window = Window(window);
+    let Some(codepoint) = char::from_u32(codepoint) else {
+        return;
+    };
+    call_window_callback(&CHAR_CALLBACKS, window, |cb| cb(window, codepoint));
+}
+
+/// Sets the Unicode character callback for the specified window. It
+/// is called for every Unicode code point entered by the user. Unlike
+/// the character with modifiers callback, it does not report modifier
+/// keys.
+pub fn set_char_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, char) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnChar>);
+    let cb = set_window_callback(
+        &CHAR_CALLBACKS,
+        window,
+        callback,
+        char_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetCharCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Unicode character with modifiers callback.
+pub type FnCharMods = dyn FnMut(Window, char, i32) + Send;
+
+static CHAR_MODS_CALLBACKS: WindowCallbacks<FnCharMods> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn char_mods_callback(window: *mut c_void, codepoint: c_uint, mods: c_int) {
+    let window = Window(window);
+    let Some(codepoint) = char::from_u32(codepoint) else {
+        return;
+    };
+    call_window_callback(&CHAR_MODS_CALLBACKS, window, |cb| {
+        cb(window, codepoint, mods)
+    });
+}
+
+/// Sets the Unicode character with modifiers callback for the
+/// specified window. It is called for every Unicode code point
+/// entered by the user, including the ones composed by input methods.
+pub fn set_char_mods_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, char, i32) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnCharMods>);
+    let cb = set_window_callback(
+        &CHAR_MODS_CALLBACKS,
+        window,
+        callback,
+        char_mods_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetCharModsCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Sets the system clipboard to the specified UTF-8 encoded string.
+pub fn set_clipboard_string(window: Window, string: &str) -> Result<()> {
+    let string = CString::new(string)?;
+    unsafe { ffi::glfwSetClipboardString(window.as_mut_ptr(), string.as_ptr()) };
+    Ok(())
+}
+
+/// Sets the cursor shown when the cursor is over the content area of
+/// the specified window. If `cursor` is `None`, the default arrow
+/// cursor is used.
+pub fn set_cursor(window: Window, cursor: Option<Cursor>) {
+    let cursor = cursor.map_or(ptr::null_mut(), |c| c.as_mut_ptr());
+    unsafe { ffi::glfwSetCursor(window.as_mut_ptr(), cursor) }
+}
+
+/// Cursor position callback.
+pub type FnCursorPos = dyn FnMut(Window, f64, f64) + Send;
+
+static CURSOR_POS_CALLBACKS: WindowCallbacks<FnCursorPos> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn cursor_pos_callback(window: *mut c_void, x: c_double, y: c_double) {
+    let window = Window(window);
+    call_window_callback(&CURSOR_POS_CALLBACKS, window, |cb| cb(window, x, y));
+}
+
+/// Sets the cursor position callback for the specified window. It
+/// is called when the cursor moves, with its position in screen
+/// coordinates relative to the upper-left corner of the content area.
+pub fn set_cursor_pos_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, f64, f64) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnCursorPos>);
+    let cb = set_window_callback(
+        &CURSOR_POS_CALLBACKS,
+        window,
+        callback,
+        cursor_pos_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetCursorPosCallback(window.as_mut_ptr(), cb) };
+}
+
+/// File drop callback.
+pub type FnDrop = dyn FnMut(Window, &[PathBuf]) + Send;
+
+static DROP_CALLBACKS: WindowCallbacks<FnDrop> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn drop_callback(window: *mut c_void, count: c_int, paths: *const *const c_char) {
+    let window = Window(window);
+    let paths = (0..count as usize)
+        .map(|i| {
+            let path = unsafe { CStr::from_ptr(*paths.add(i)) };
+            PathBuf::from(path.to_string_lossy().into_owned())
+        })
+        .collect::<Vec<_>>();
+    call_window_callback(&DROP_CALLBACKS, window, |cb| cb(window, &paths));
+}
+
+/// Sets the file drop callback for the specified window. It is called
+/// when one or more dragged paths are dropped on the window.
+pub fn set_drop_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, &[PathBuf]) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnDrop>);
+    let cb = set_window_callback(
+        &DROP_CALLBACKS,
+        window,
+        callback,
+        drop_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetDropCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Error callback.
+pub type FnError = dyn FnMut(ErrorCode, &str) + Send;
+
+static ERROR_CALLBACK: GlobalCallback<FnError> = Mutex::new(CallbackSlot::new());
+
+extern "C" fn error_callback(error_code: c_int, description: *const c_char) {
+    let description = unsafe { CStr::from_ptr(description) }
+        .to_str()
+        .expect("GLFW error description is not a valid UTF-8 string");
+    call_global_callback(&ERROR_CALLBACK, |cb| cb(error_code.into(), description));
+}
+
+/// Sets the error callback.
+pub fn set_error_callback<F>(callback: Option<F>)
+where
+    F: FnMut(ErrorCode, &str) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnError>);
+    let cb = set_global_callback(&ERROR_CALLBACK, callback, error_callback as *const c_void);
+    unsafe { ffi::glfwSetErrorCallback(cb) };
+}
+
+/// Framebuffer size change callback.
+pub type FnFramebufferSize = dyn FnMut(Window, i32, i32) + Send;
+
+static FRAMEBUFFER_SIZE_CALLBACKS: WindowCallbacks<FnFramebufferSize> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn framebuffer_size_callback(window: *mut c_void, width: c_int, height: c_int) {
+    let window = Window(window);
+    call_window_callback(&FRAMEBUFFER_SIZE_CALLBACKS, window, |cb| {
+        cb(window, width, height)
+    });
+}
+
+/// Sets the framebuffer resize callback for the specified window.
+pub fn set_framebuffer_size_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, i32, i32) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnFramebufferSize>);
+    let cb = set_window_callback(
+        &FRAMEBUFFER_SIZE_CALLBACKS,
+        window,
+        callback,
+        framebuffer_size_callback as *const c_void,
+    );
     unsafe { ffi::glfwSetFramebufferSizeCallback(window.as_mut_ptr(), cb) };
 }
 
+/// Generates a gamma ramp from the specified exponent and sets it for
+/// the specified monitor. The exponent must be greater than zero.
+pub fn set_gamma(monitor: Monitor, gamma: f32) {
+    unsafe { ffi::glfwSetGamma(monitor.as_mut_ptr(), gamma) }
+}
+
+/// Sets the gamma ramp of the specified monitor. All the channels must
+/// have the same size.
+pub fn set_gamma_ramp(monitor: Monitor, ramp: &GammaRamp) -> Result<()> {
+    let size = ramp.red.len();
+    if ramp.green.len() != size || ramp.blue.len() != size {
+        return Err(Error::GammaRampSize);
+    }
+    let ramp = GlfwGammaRamp {
+        red: ramp.red.as_ptr(),
+        green: ramp.green.as_ptr(),
+        blue: ramp.blue.as_ptr(),
+        size: size as c_uint,
+    };
+    unsafe { ffi::glfwSetGammaRamp(monitor.as_mut_ptr(), &ramp) };
+    Ok(())
+}
+
+/// Sets an input mode for the specified window.
+pub fn set_input_mode(window: Window, mode: InputMode) {
+    let (mode, value) = mode.mode_value();
+    unsafe { ffi::glfwSetInputMode(window.as_mut_ptr(), mode, value) }
+}
+
+/// Joystick configuration change callback.
+pub type FnJoystick = dyn FnMut(i32, JoystickEvent) + Send;
+
+static JOYSTICK_CALLBACK: GlobalCallback<FnJoystick> = Mutex::new(CallbackSlot::new());
+
+extern "C" fn joystick_callback(jid: c_int, event: c_int) {
+    call_global_callback(&JOYSTICK_CALLBACK, |cb| cb(jid, event.into()));
+}
+
+/// Sets the joystick configuration callback, which is called when a
+/// joystick is connected to or disconnected from the system.
+pub fn set_joystick_callback<F>(callback: Option<F>)
+where
+    F: FnMut(i32, JoystickEvent) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnJoystick>);
+    let cb = set_global_callback(
+        &JOYSTICK_CALLBACK,
+        callback,
+        joystick_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetJoystickCallback(cb) };
+}
+
+/// Key callback.
+pub type FnKey = dyn FnMut(Window, Key, i32, Action, i32) + Send;
+
+static KEY_CALLBACKS: WindowCallbacks<FnKey> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn key_callback(
+    window: *mut c_void,
+    key: c_int,
+    scancode: c_int,
+    action: c_int,
+    mods: c_int,
+) {
+    let window = Window(window);
+    call_window_callback(&KEY_CALLBACKS, window, |cb| {
+        cb(window, key.into(), scancode, action.into(), mods)
+    });
+}
+
+/// Sets the key callback for the specified window. It is called with
+/// the key, its platform-specific scancode, the action and the
+/// modifier bits when a key is pressed, repeated or released.
+pub fn set_key_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, Key, i32, Action, i32) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnKey>);
+    let cb = set_window_callback(
+        &KEY_CALLBACKS,
+        window,
+        callback,
+        key_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetKeyCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Monitor configuration change callback.
+pub type FnMonitor = dyn FnMut(Monitor, MonitorEvent) + Send;
+
+static MONITOR_CALLBACK: GlobalCallback<FnMonitor> = Mutex::new(CallbackSlot::new());
+
+extern "C" fn monitor_callback(monitor: *mut c_void, event: c_int) {
+    call_global_callback(&MONITOR_CALLBACK, |cb| cb(Monitor(monitor), event.into()));
+}
+
+/// Sets the monitor configuration callback, which is called when a
+/// monitor is connected to or disconnected from the system.
+pub fn set_monitor_callback<F>(callback: Option<F>)
+where
+    F: FnMut(Monitor, MonitorEvent) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnMonitor>);
+    let cb = set_global_callback(
+        &MONITOR_CALLBACK,
+        callback,
+        monitor_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetMonitorCallback(cb) };
+}
+
+/// Sets the value of the GLFW timer. It then continues to count up
+/// from that value.
+pub fn set_time(time: f64) {
+    unsafe { ffi::glfwSetTime(time) }
+}
+
+/// Sets the required aspect ratio of the content area of the
+/// specified window. If `ratio` is `None`, the aspect ratio is not
+/// constrained.
+pub fn set_window_aspect_ratio(window: Window, ratio: Option<(i32, i32)>) {
+    let (numer, denom) = ratio.unwrap_or((DONT_CARE, DONT_CARE));
+    unsafe { ffi::glfwSetWindowAspectRatio(window.as_mut_ptr(), numer, denom) }
+}
+
+/// Window close request callback.
+pub type FnWindowClose = dyn FnMut(Window) + Send;
+
+static WINDOW_CLOSE_CALLBACKS: WindowCallbacks<FnWindowClose> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn window_close_callback(window: *mut c_void) {
+    let window = Window(window);
+    call_window_callback(&WINDOW_CLOSE_CALLBACKS, window, |cb| cb(window));
+}
+
+/// Sets the close callback for the specified window. It is called
+/// when the user attempts to close the window, after the close flag
+/// has been set. The flag can be cleared with
+/// [`set_window_should_close`] to cancel the request.
+pub fn set_window_close_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnWindowClose>);
+    let cb = set_window_callback(
+        &WINDOW_CLOSE_CALLBACKS,
+        window,
+        callback,
+        window_close_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetWindowCloseCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Window focus change callback.
+pub type FnWindowFocus = dyn FnMut(Window, bool) + Send;
+
+static WINDOW_FOCUS_CALLBACKS: WindowCallbacks<FnWindowFocus> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn window_focus_callback(window: *mut c_void, focused: c_int) {
+    let window = Window(window);
+    call_window_callback(&WINDOW_FOCUS_CALLBACKS, window, |cb| {
+        cb(window, focused != 0)
+    });
+}
+
+/// Sets the focus callback for the specified window. It is called
+/// when the window gains or loses input focus.
+pub fn set_window_focus_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, bool) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnWindowFocus>);
+    let cb = set_window_callback(
+        &WINDOW_FOCUS_CALLBACKS,
+        window,
+        callback,
+        window_focus_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetWindowFocusCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Sets the icon of the specified window. The system picks the
+/// candidate image closest to the sizes it needs. If `images` is
+/// empty, the window reverts to its default icon.
+pub fn set_window_icon(window: Window, images: &[Image]) {
+    let pixels = images.iter().map(rgba_pixels).collect::<Vec<_>>();
+    let images = images
+        .iter()
+        .zip(&pixels)
+        .map(|(image, pixels)| GlfwImage {
+            width: image.width() as c_int,
+            height: image.height() as c_int,
+            pixels: pixels.as_ptr(),
+        })
+        .collect::<Vec<_>>();
+    let ptr = if images.is_empty() {
+        ptr::null()
+    } else {
+        images.as_ptr()
+    };
+    unsafe { ffi::glfwSetWindowIcon(window.as_mut_ptr(), images.len() as c_int, ptr) };
+}
+
+/// Window iconification callback.
+pub type FnWindowIconify = dyn FnMut(Window, bool) + Send;
+
+static WINDOW_ICONIFY_CALLBACKS: WindowCallbacks<FnWindowIconify> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn window_iconify_callback(window: *mut c_void, iconified: c_int) {
+    let window = Window(window);
+    call_window_callback(&WINDOW_ICONIFY_CALLBACKS, window, |cb| {
+        cb(window, iconified != 0)
+    });
+}
+
+/// Sets the iconification callback for the specified window. It is
+/// called when the window is iconified (minimized) or restored.
+pub fn set_window_iconify_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, bool) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnWindowIconify>);
+    let cb = set_window_callback(
+        &WINDOW_ICONIFY_CALLBACKS,
+        window,
+        callback,
+        window_iconify_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetWindowIconifyCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Window maximization callback.
+pub type FnWindowMaximize = dyn FnMut(Window, bool) + Send;
+
+static WINDOW_MAXIMIZE_CALLBACKS: WindowCallbacks<FnWindowMaximize> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn window_maximize_callback(window: *mut c_void, maximized: c_int) {
+    let window = Window(window);
+    call_window_callback(&WINDOW_MAXIMIZE_CALLBACKS, window, |cb| {
+        cb(window, maximized != 0)
+    });
+}
+
+/// Sets the maximization callback for the specified window. It is
+/// called when the window is maximized or restored.
+pub fn set_window_maximize_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, bool) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnWindowMaximize>);
+    let cb = set_window_callback(
+        &WINDOW_MAXIMIZE_CALLBACKS,
+        window,
+        callback,
+        window_maximize_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetWindowMaximizeCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Sets the monitor that the specified window uses for full screen
+/// mode or, if `monitor` is `None`, makes it windowed mode. When
+/// switching to windowed mode, `x` and `y` set the position of the
+/// content area. When switching to full screen mode, they are ignored
+/// and `refresh_rate` selects the refresh rate, in Hz. Pass
+/// [`DONT_CARE`] to use the highest available refresh rate.
+pub fn set_window_monitor(
+    window: Window,
+    monitor: Option<Monitor>,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    refresh_rate: i32,
+) {
+    let monitor = monitor.map_or(ptr::null_mut(), |m| m.as_mut_ptr());
+    unsafe {
+        ffi::glfwSetWindowMonitor(
+            window.as_mut_ptr(),
+            monitor,
+            x,
+            y,
+            width,
+            height,
+            refresh_rate,
+        )
+    };
+}
+
+/// Sets the position, in screen coordinates, of the upper-left corner
+/// of the content area of the specified window.
+pub fn set_window_pos(window: Window, x: i32, y: i32) {
+    unsafe { ffi::glfwSetWindowPos(window.as_mut_ptr(), x, y) }
+}
+
+/// Sets the close flag of the specified window. Setting it to `true`
+/// makes [`window_should_close`] return `true`, so the main loop exits
+/// through its normal path.
+pub fn set_window_should_close(window: Window, value: bool) {
+    unsafe { ffi::glfwSetWindowShouldClose(window.as_mut_ptr(), value as c_int) }
+}
+
+/// Sets the size, in screen coordinates, of the content area of the
+/// specified window.
+pub fn set_window_size(window: Window, width: i32, height: i32) {
+    unsafe { ffi::glfwSetWindowSize(window.as_mut_ptr(), width, height) }
+}
+
+/// Window size change callback.
+pub type FnWindowSize = dyn FnMut(Window, i32, i32) + Send;
+
+static WINDOW_SIZE_CALLBACKS: WindowCallbacks<FnWindowSize> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+extern "C" fn window_size_callback(window: *mut c_void, width: c_int, height: c_int) {
+    let window = Window(window);
+    call_window_callback(&WINDOW_SIZE_CALLBACKS, window, |cb| {
+        cb(window, width, height)
+    });
+}
+
+/// Sets the size callback for the specified window. It is called when
+/// the window is resized and receives the new size, in screen
+/// coordinates, of the content area of the window.
+pub fn set_window_size_callback<F>(window: Window, callback: Option<F>)
+where
+    F: FnMut(Window, i32, i32) + Send + 'static,
+{
+    let callback = callback.map(|f| Box::new(f) as Box<FnWindowSize>);
+    let cb = set_window_callback(
+        &WINDOW_SIZE_CALLBACKS,
+        window,
+        callback,
+        window_size_callback as *const c_void,
+    );
+    unsafe { ffi::glfwSetWindowSizeCallback(window.as_mut_ptr(), cb) };
+}
+
+/// Sets the size limits of the content area of the specified window.
+/// If `min` or `max` is `None`, the corresponding limit is disabled.
+pub fn set_window_size_limits(window: Window, min: Option<(i32, i32)>, max: Option<(i32, i32)>) {
+    let (minwidth, minheight) = min.unwrap_or((DONT_CARE, DONT_CARE));
+    let (maxwidth, maxheight) = max.unwrap_or((DONT_CARE, DONT_CARE));
+    unsafe {
+        ffi::glfwSetWindowSizeLimits(
+            window.as_mut_ptr(),
+            minwidth,
+            minheight,
+            maxwidth,
+            maxheight,
+        )
+    };
+}
+
+/// Sets the title of the specified window.
+pub fn set_window_title(window: Window, title: &str) -> Result<()> {
+    let title = CString::new(title)?;
+    unsafe { ffi::glfwSetWindowTitle(window.as_mut_ptr(), title.as_ptr()) };
+    Ok(())
+}
+
+/// Sets the user data of the specified window, dropping the previous
+/// one, if any.
+pub fn set_window_user_data<T: Any>(window: Window, data: T) {
+    drop(take_user_data(window));
+    let data = Box::into_raw(Box::new(Box::new(data) as Box<dyn Any>));
+    unsafe { ffi::glfwSetWindowUserPointer(window.as_mut_ptr(), data as *mut c_void) };
+}
+
+/// Makes the specified window visible, if it was previously hidden.
+pub fn show_window(window: Window) {
+    unsafe { ffi::glfwShowWindow(window.as_mut_ptr()) }
+}
+
 /// Swaps the front and back buffers of the specified window.
 pub fn swap_buffers(window: Window) {
     unsafe { ffi::glfwSwapBuffers(window.as_mut_ptr()) }
 }
 
-/// Sets the specified window hint to the desired value.
-pub fn window_hint(hint: i32, value: i32) {
+/// Removes and returns the user data of the specified window. If the
+/// user data is not of type `T`, it is left in place and `None` is
+/// returned.
+pub fn take_window_user_data<T: Any>(window: Window) -> Option<T> {
+    let data = take_user_data(window)?;
+    match data.downcast::<T>() {
+        Ok(data) => Some(*data),
+        Err(data) => {
+            restore_user_data(window, data);
+            None
+        }
+    }
+}
+
+/// Takes the user data out of the specified window, leaving a null
+/// user pointer.
+fn take_user_data(window: Window) -> Option<Box<dyn Any>> {
+    let data = unsafe { ffi::glfwGetWindowUserPointer(window.as_mut_ptr()) };
+    if data.is_null() {
+        return None;
+    }
+    unsafe { ffi::glfwSetWindowUserPointer(window.as_mut_ptr(), ptr::null_mut()) };
+    Some(*unsafe { Box::from_raw(data as *mut Box<dyn Any>) })
+}
+
+/// Stores back user data previously taken with [`take_user_data`].
+fn restore_user_data(window: Window, data: Box<dyn Any>) {
+    let data = Box::into_raw(Box::new(data));
+    unsafe { ffi::glfwSetWindowUserPointer(window.as_mut_ptr(), data as *mut c_void) };
+}
+
+/// Windowed mode geometry of the windows that are in full screen mode,
+/// used to restore them.
+static WINDOWED_GEOMETRY: LazyLock<Mutex<HashMap<Window, [i32; 4]>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Toggles the specified window between windowed mode and full screen
+/// mode on the primary monitor, using its current video mode. The
+/// windowed mode position and size are restored when switching back.
+pub fn toggle_fullscreen(window: Window) {
+    if get_window_monitor(window).is_some() {
+        let geometry = WINDOWED_GEOMETRY.lock().unwrap().remove(&window);
+        let [x, y, width, height] = geometry.unwrap_or([DONT_CARE, DONT_CARE, 800, 600]);
+        set_window_monitor(window, None, x, y, width, height, DONT_CARE);
+        return;
+    }
+
+    let Some(monitor) = get_primary_monitor() else {
+        return;
+    };
+    let Some(mode) = get_video_mode(monitor) else {
+        return;
+    };
+
+    let (x, y) = get_window_pos(window);
+    let (width, height) = get_window_size(window);
+    WINDOWED_GEOMETRY
+        .lock()
+        .unwrap()
+        .insert(window, [x, y, width, height]);
+
+    set_window_monitor(
+        window,
+        Some(monitor),
+        0,
+        0,
+        mode.width,
+        mode.height,
+        mode.refresh_rate,
+    );
+}
+
+/// Returns whether a Vulkan loader and a minimally functional ICD have
+/// been found.
+pub fn vulkan_supported() -> bool {
+    unsafe { ffi::glfwVulkanSupported() != 0 }
+}
+
+/// Puts the calling thread to sleep until at least one event is
+/// available in the event queue, and then processes all pending
+/// events.
+pub fn wait_events() {
+    unsafe { ffi::glfwWaitEvents() }
+}
+
+/// Like [`wait_events`], but returns after `timeout` seconds if no
+/// event has been received.
+pub fn wait_events_timeout(timeout: f64) {
+    unsafe { ffi::glfwWaitEventsTimeout(timeout) }
+}
+
+/// Sets the specified window hint for the next call to
+/// [`create_window`].
+pub fn window_hint(hint: WindowHint) {
+    let (hint, value) = hint.hint_value();
     unsafe { ffi::glfwWindowHint(hint, value) }
 }
 
@@ -243,3 +2018,375 @@ pub fn window_hint(hint: i32, value: i32) {
 pub fn window_should_close(window: Window) -> bool {
     unsafe { ffi::glfwWindowShouldClose(window.as_mut_ptr()) != 0 }
 }
+
+/// Calls `f` with a mutable reference to the user data of the
+/// specified window and returns its result. It returns `None` if the
+/// window has no user data or it is not of type `T`. The user data is
+/// detached from the window while `f` runs, so nested calls for the
+/// same window return `None`.
+pub fn with_window_user_data<T: Any, R>(window: Window, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+    let mut data = take_user_data(window)?;
+    let result = data.downcast_mut::<T>().map(f);
+    if unsafe { ffi::glfwGetWindowUserPointer(window.as_mut_ptr()) }.is_null() {
+        restore_user_data(window, data);
+    }
+    result
+}
+
+/// Set of windows sharing their GL objects, e.g. a projector output and
+/// an operator UI. Every window created through the set shares the
+/// context of the first one, and the set keeps track of the current
+/// context to avoid redundant context switches.
+#[derive(Default)]
+pub struct WindowSet {
+    windows: Vec<Window>,
+    current: Option<Window>,
+}
+
+impl WindowSet {
+    /// Creates an empty window set.
+    pub fn new() -> WindowSet {
+        WindowSet::default()
+    }
+
+    /// Creates a window whose context shares objects with the other
+    /// windows in the set, and adds it to the set.
+    pub fn create_window(
+        &mut self,
+        width: i32,
+        height: i32,
+        title: &str,
+        monitor: Option<Monitor>,
+    ) -> Result<Window> {
+        let window = create_window(width, height, title, monitor, self.windows.first().copied())?;
+        self.windows.push(window);
+        Ok(window)
+    }
+
+    /// Returns the windows in the set, in creation order.
+    pub fn windows(&self) -> &[Window] {
+        &self.windows
+    }
+
+    /// Returns true if the set contains no windows.
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Returns the window whose context has been made current through
+    /// the set.
+    pub fn current(&self) -> Option<Window> {
+        self.current
+    }
+
+    /// Makes the context of the specified window current, unless it
+    /// already is.
+    pub fn make_current(&mut self, window: Window) {
+        if self.current != Some(window) {
+            make_context_current(window);
+            self.current = Some(window);
+        }
+    }
+
+    /// Destroys the windows whose close flag is set and removes them
+    /// from the set.
+    pub fn destroy_closed(&mut self) {
+        let current = &mut self.current;
+        self.windows.retain(|&window| {
+            if !window_should_close(window) {
+                return true;
+            }
+            if *current == Some(window) {
+                *current = None;
+            }
+            destroy_window(window);
+            false
+        });
+    }
+
+    /// Runs the event loop until all the windows are closed. For every
+    /// window and frame, it makes the window context current, calls
+    /// `frame` and swaps the window buffers.
+    pub fn run<F: FnMut(Window)>(&mut self, mut frame: F) {
+        while !self.is_empty() {
+            poll_events();
+            self.destroy_closed();
+            for window in self.windows.clone() {
+                self.make_current(window);
+                frame(window);
+                swap_buffers(window);
+            }
+        }
+    }
+}
+
+/// Native access to the underlying window system and context objects.
+/// Each function is only available on the platforms that provide the
+/// corresponding object, and requires GLFW to be built with support
+/// for it.
+#[cfg(feature = "native")]
+pub mod native {
+    use std::ffi::c_void;
+
+    use super::Window;
+
+    #[allow(non_snake_case)]
+    mod ffi {
+        use std::ffi::c_void;
+
+        #[link(name = "glfw")]
+        extern "C" {
+            #[cfg(target_os = "macos")]
+            pub fn glfwGetCocoaView(window: *mut c_void) -> *mut c_void;
+            #[cfg(target_os = "macos")]
+            pub fn glfwGetCocoaWindow(window: *mut c_void) -> *mut c_void;
+            pub fn glfwGetEGLContext(window: *mut c_void) -> *mut c_void;
+            pub fn glfwGetEGLDisplay() -> *mut c_void;
+            pub fn glfwGetEGLSurface(window: *mut c_void) -> *mut c_void;
+            #[cfg(all(unix, not(target_os = "macos")))]
+            pub fn glfwGetGLXContext(window: *mut c_void) -> *mut c_void;
+            #[cfg(all(unix, not(target_os = "macos")))]
+            pub fn glfwGetGLXWindow(window: *mut c_void) -> std::ffi::c_ulong;
+            #[cfg(target_os = "macos")]
+            pub fn glfwGetNSGLContext(window: *mut c_void) -> *mut c_void;
+            #[cfg(windows)]
+            pub fn glfwGetWGLContext(window: *mut c_void) -> *mut c_void;
+            #[cfg(all(unix, not(target_os = "macos")))]
+            pub fn glfwGetWaylandDisplay() -> *mut c_void;
+            #[cfg(all(unix, not(target_os = "macos")))]
+            pub fn glfwGetWaylandWindow(window: *mut c_void) -> *mut c_void;
+            #[cfg(windows)]
+            pub fn glfwGetWin32Window(window: *mut c_void) -> *mut c_void;
+            #[cfg(all(unix, not(target_os = "macos")))]
+            pub fn glfwGetX11Display() -> *mut c_void;
+            #[cfg(all(unix, not(target_os = "macos")))]
+            pub fn glfwGetX11Window(window: *mut c_void) -> std::ffi::c_ulong;
+        }
+    }
+
+    /// Returns the content `NSView` of the specified window.
+    #[cfg(target_os = "macos")]
+    pub fn get_cocoa_view(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetCocoaView(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `NSWindow` of the specified window.
+    #[cfg(target_os = "macos")]
+    pub fn get_cocoa_window(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetCocoaWindow(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `EGLContext` of the specified window.
+    pub fn get_egl_context(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetEGLContext(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `EGLDisplay` used by GLFW.
+    pub fn get_egl_display() -> *mut c_void {
+        unsafe { ffi::glfwGetEGLDisplay() }
+    }
+
+    /// Returns the `EGLSurface` of the specified window.
+    pub fn get_egl_surface(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetEGLSurface(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `GLXContext` of the specified window.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub fn get_glx_context(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetGLXContext(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `GLXWindow` of the specified window.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub fn get_glx_window(window: Window) -> std::ffi::c_ulong {
+        unsafe { ffi::glfwGetGLXWindow(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `NSOpenGLContext` of the specified window.
+    #[cfg(target_os = "macos")]
+    pub fn get_nsgl_context(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetNSGLContext(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `struct wl_display*` used by GLFW.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub fn get_wayland_display() -> *mut c_void {
+        unsafe { ffi::glfwGetWaylandDisplay() }
+    }
+
+    /// Returns the `struct wl_surface*` of the specified window.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub fn get_wayland_surface(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetWaylandWindow(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `HGLRC` of the specified window.
+    #[cfg(windows)]
+    pub fn get_wgl_context(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetWGLContext(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `HWND` of the specified window.
+    #[cfg(windows)]
+    pub fn get_win32_window(window: Window) -> *mut c_void {
+        unsafe { ffi::glfwGetWin32Window(window.as_mut_ptr()) }
+    }
+
+    /// Returns the `Display*` used by GLFW.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub fn get_x11_display() -> *mut c_void {
+        unsafe { ffi::glfwGetX11Display() }
+    }
+
+    /// Returns the `Window` of the specified window.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub fn get_x11_window(window: Window) -> std::ffi::c_ulong {
+        unsafe { ffi::glfwGetX11Window(window.as_mut_ptr()) }
+    }
+}
+
+/// [raw-window-handle](https://docs.rs/raw-window-handle) integration.
+/// X11, Wayland, Win32 and Cocoa are supported. Other platforms return
+/// [`HandleError::NotSupported`].
+///
+/// [`HandleError::NotSupported`]: raw_window_handle::HandleError::NotSupported
+#[cfg(feature = "raw-window-handle")]
+mod handle {
+    use std::ptr::NonNull;
+
+    use raw_window_handle::{
+        DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle,
+        RawWindowHandle, WindowHandle,
+    };
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    use std::{
+        ffi::{c_char, c_int, c_ulong, c_void, CStr},
+        mem, ptr,
+    };
+
+    use super::Window;
+
+    #[cfg(any(windows, target_os = "macos"))]
+    use super::native;
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[link(name = "X11")]
+    extern "C" {
+        fn XDefaultScreen(display: *mut c_void) -> c_int;
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    extern "C" {
+        fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    }
+
+    /// Looks up a GLFW native function at runtime. Before GLFW 3.4,
+    /// the library only provides the native functions of the platform
+    /// it was built for, so they cannot be linked directly.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn native_fn<F: Copy>(name: &CStr) -> Option<F> {
+        // RTLD_DEFAULT.
+        let f = unsafe { dlsym(ptr::null_mut(), name.as_ptr()) };
+        (!f.is_null()).then(|| unsafe { mem::transmute_copy(&f) })
+    }
+
+    /// Reports whether GLFW is expected to use Wayland. It is only a
+    /// hint used to choose which native functions are tried first,
+    /// given that `glfwGetPlatform` requires GLFW 3.4.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn prefer_wayland() -> bool {
+        std::env::var_os("WAYLAND_DISPLAY").is_some()
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn raw_window_handle(window: Window) -> Result<RawWindowHandle, HandleError> {
+        let x11 = || {
+            let get: unsafe extern "C" fn(*mut c_void) -> c_ulong = native_fn(c"glfwGetX11Window")?;
+            let xid = unsafe { get(window.as_mut_ptr()) };
+            (xid != 0).then(|| raw_window_handle::XlibWindowHandle::new(xid).into())
+        };
+        let wayland = || {
+            let get: unsafe extern "C" fn(*mut c_void) -> *mut c_void =
+                native_fn(c"glfwGetWaylandWindow")?;
+            NonNull::new(unsafe { get(window.as_mut_ptr()) })
+                .map(|surface| raw_window_handle::WaylandWindowHandle::new(surface).into())
+        };
+        let handle = if prefer_wayland() {
+            wayland().or_else(x11)
+        } else {
+            x11().or_else(wayland)
+        };
+        handle.ok_or(HandleError::Unavailable)
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    fn raw_display_handle() -> Result<RawDisplayHandle, HandleError> {
+        let x11 = || {
+            let get: unsafe extern "C" fn() -> *mut c_void = native_fn(c"glfwGetX11Display")?;
+            NonNull::new(unsafe { get() }).map(|display| {
+                let screen = unsafe { XDefaultScreen(display.as_ptr()) };
+                raw_window_handle::XlibDisplayHandle::new(Some(display), screen).into()
+            })
+        };
+        let wayland = || {
+            let get: unsafe extern "C" fn() -> *mut c_void = native_fn(c"glfwGetWaylandDisplay")?;
+            NonNull::new(unsafe { get() })
+                .map(|display| raw_window_handle::WaylandDisplayHandle::new(display).into())
+        };
+        let handle = if prefer_wayland() {
+            wayland().or_else(x11)
+        } else {
+            x11().or_else(wayland)
+        };
+        handle.ok_or(HandleError::Unavailable)
+    }
+
+    #[cfg(windows)]
+    fn raw_window_handle(window: Window) -> Result<RawWindowHandle, HandleError> {
+        let hwnd = native::get_win32_window(window);
+        let hwnd = std::num::NonZeroIsize::new(hwnd as isize).ok_or(HandleError::Unavailable)?;
+        Ok(raw_window_handle::Win32WindowHandle::new(hwnd).into())
+    }
+
+    #[cfg(windows)]
+    fn raw_display_handle() -> Result<RawDisplayHandle, HandleError> {
+        Ok(raw_window_handle::WindowsDisplayHandle::new().into())
+    }
+
+    #[cfg(target_os = "macos")]
+    fn raw_window_handle(window: Window) -> Result<RawWindowHandle, HandleError> {
+        let view = NonNull::new(native::get_cocoa_view(window)).ok_or(HandleError::Unavailable)?;
+        Ok(raw_window_handle::AppKitWindowHandle::new(view).into())
+    }
+
+    #[cfg(target_os = "macos")]
+    fn raw_display_handle() -> Result<RawDisplayHandle, HandleError> {
+        Ok(raw_window_handle::AppKitDisplayHandle::new().into())
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn raw_window_handle(_window: Window) -> Result<RawWindowHandle, HandleError> {
+        Err(HandleError::NotSupported)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn raw_display_handle() -> Result<RawDisplayHandle, HandleError> {
+        Err(HandleError::NotSupported)
+    }
+
+    impl HasWindowHandle for Window {
+        fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+            let raw = raw_window_handle(*self)?;
+            Ok(unsafe { WindowHandle::borrow_raw(raw) })
+        }
+    }
+
+    impl HasDisplayHandle for Window {
+        fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+            let raw = raw_display_handle()?;
+            Ok(unsafe { DisplayHandle::borrow_raw(raw) })
+        }
+    }
+}
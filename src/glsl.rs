@@ -0,0 +1,619 @@
+//! GLSL utilities.
+
+use std::{error, fmt, result};
+
+/// A specialized result type.
+pub type Result<T> = result::Result<T, Error>;
+
+/// GLSL error.
+#[derive(Debug)]
+pub enum Error {
+    /// The source does not contain a uniform block.
+    MissingUniformBlock,
+
+    /// Malformed uniform block declaration.
+    Parse(String),
+
+    /// Unsupported member type or qualifier.
+    Unsupported(String),
+
+    /// Unknown uniform block member.
+    UnknownMember(String),
+
+    /// The layout of a Rust type does not match the layout of the
+    /// uniform block.
+    LayoutMismatch {
+        /// Name of the mismatching member. It is empty if the
+        /// mismatch affects the size of the whole block.
+        member: String,
+
+        /// Expected offset or size.
+        expected: usize,
+
+        /// Actual offset or size.
+        found: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingUniformBlock => write!(f, "missing uniform block"),
+            Error::Parse(s) => write!(f, "malformed uniform block: {s}"),
+            Error::Unsupported(s) => write!(f, "unsupported declaration: {s}"),
+            Error::UnknownMember(s) => write!(f, "unknown uniform block member: {s}"),
+            Error::LayoutMismatch {
+                member,
+                expected,
+                found,
+            } => {
+                if member.is_empty() {
+                    write!(f, "block size mismatch: expected {expected}, found {found}")
+                } else {
+                    write!(
+                        f,
+                        "offset mismatch in {member}: expected {expected}, found {found}"
+                    )
+                }
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Scalar type of a uniform block member.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scalar {
+    /// `float`.
+    Float,
+
+    /// `int`.
+    Int,
+
+    /// `uint`.
+    Uint,
+
+    /// `bool`.
+    Bool,
+}
+
+impl Scalar {
+    /// Returns the Rust type used to represent the scalar. GLSL
+    /// booleans are 4 bytes long, so they are represented as `u32`.
+    fn rust_type(self) -> &'static str {
+        match self {
+            Scalar::Float => "f32",
+            Scalar::Int => "i32",
+            Scalar::Uint | Scalar::Bool => "u32",
+        }
+    }
+
+    /// Returns the Rust expression of the zero value of the scalar.
+    fn rust_zero(self) -> &'static str {
+        match self {
+            Scalar::Float => "0.0",
+            Scalar::Int | Scalar::Uint | Scalar::Bool => "0",
+        }
+    }
+}
+
+/// Type of a uniform block member.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Type {
+    /// Scalar type.
+    Scalar(Scalar),
+
+    /// Vector type with the specified number of components.
+    Vector(Scalar, usize),
+
+    /// Column-major float matrix with the specified number of
+    /// columns and rows.
+    Matrix(usize, usize),
+}
+
+impl Type {
+    /// Parses a GLSL type name.
+    fn parse(s: &str) -> Option<Type> {
+        let scalar = match s {
+            "float" => Some(Scalar::Float),
+            "int" => Some(Scalar::Int),
+            "uint" => Some(Scalar::Uint),
+            "bool" => Some(Scalar::Bool),
+            _ => None,
+        };
+        if let Some(scalar) = scalar {
+            return Some(Type::Scalar(scalar));
+        }
+
+        let dim = |d: &str| match d {
+            "2" => Some(2),
+            "3" => Some(3),
+            "4" => Some(4),
+            _ => None,
+        };
+
+        if let Some(rest) = s.strip_prefix("mat") {
+            return match rest.split_once('x') {
+                Some((cols, rows)) => Some(Type::Matrix(dim(cols)?, dim(rows)?)),
+                None => dim(rest).map(|n| Type::Matrix(n, n)),
+            };
+        }
+
+        let (scalar, rest) = if let Some(rest) = s.strip_prefix("vec") {
+            (Scalar::Float, rest)
+        } else if let Some(rest) = s.strip_prefix("ivec") {
+            (Scalar::Int, rest)
+        } else if let Some(rest) = s.strip_prefix("uvec") {
+            (Scalar::Uint, rest)
+        } else if let Some(rest) = s.strip_prefix("bvec") {
+            (Scalar::Bool, rest)
+        } else {
+            return None;
+        };
+        dim(rest).map(|n| Type::Vector(scalar, n))
+    }
+
+    /// Returns the std140 base alignment of the type.
+    fn align(self) -> usize {
+        match self {
+            Type::Scalar(_) => 4,
+            Type::Vector(_, 2) => 8,
+            Type::Vector(_, _) => 16,
+            Type::Matrix(_, _) => 16,
+        }
+    }
+
+    /// Returns the std140 size of the type.
+    fn size(self) -> usize {
+        match self {
+            Type::Scalar(_) => 4,
+            Type::Vector(_, n) => 4 * n,
+            Type::Matrix(cols, _) => 16 * cols,
+        }
+    }
+
+    /// Returns the Rust type with the same std140 layout. If
+    /// `padded` is true, the type is extended to 16 bytes, as
+    /// required by array elements.
+    fn rust_type(self, padded: bool) -> String {
+        match self {
+            Type::Scalar(s) if padded => format!("[{}; 4]", s.rust_type()),
+            Type::Scalar(s) => s.rust_type().to_string(),
+            Type::Vector(s, _) if padded => format!("[{}; 4]", s.rust_type()),
+            Type::Vector(s, n) => format!("[{}; {n}]", s.rust_type()),
+            Type::Matrix(cols, _) => format!("[[f32; 4]; {cols}]"),
+        }
+    }
+
+    /// Returns the Rust expression of the zero value of the type
+    /// returned by [`Type::rust_type`].
+    fn rust_zero(self, padded: bool) -> String {
+        match self {
+            Type::Scalar(s) | Type::Vector(s, _) if padded => format!("[{}; 4]", s.rust_zero()),
+            Type::Scalar(s) => s.rust_zero().to_string(),
+            Type::Vector(s, n) => format!("[{}; {n}]", s.rust_zero()),
+            Type::Matrix(cols, _) => format!("[[0.0; 4]; {cols}]"),
+        }
+    }
+}
+
+/// Uniform block member.
+#[derive(Clone, Debug)]
+pub struct Member {
+    /// Member name.
+    pub name: String,
+
+    /// Member type.
+    pub typ: Type,
+
+    /// Array length, if the member is an array.
+    pub array_len: Option<usize>,
+
+    /// Offset of the member within the block.
+    pub offset: usize,
+
+    /// Size of the member.
+    pub size: usize,
+}
+
+/// Uniform block with std140 layout.
+#[derive(Clone, Debug)]
+pub struct UniformBlock {
+    /// Block name.
+    pub name: String,
+
+    /// Block members in declaration order.
+    pub members: Vec<Member>,
+
+    /// Size of the block.
+    pub size: usize,
+}
+
+impl UniformBlock {
+    /// Parses the first uniform block declared in `src` and
+    /// computes its std140 layout. Nested structures are not
+    /// supported.
+    pub fn parse(src: &str) -> Result<UniformBlock> {
+        let src = strip_comments(src);
+
+        let mut rest = src.as_str();
+        let (name, body) = loop {
+            let open = rest.find('{').ok_or(Error::MissingUniformBlock)?;
+            let close = rest[open..]
+                .find('}')
+                .ok_or_else(|| Error::Parse("missing '}'".to_string()))?
+                + open;
+            let head = rest[..open]
+                .rsplit([';', '}'])
+                .next()
+                .unwrap_or_default()
+                .split_whitespace()
+                .collect::<Vec<_>>();
+            if let Some(i) = head.iter().position(|w| *w == "uniform") {
+                match head.get(i + 1) {
+                    Some(name) if head.len() == i + 2 && is_identifier(name) => {
+                        break (*name, &rest[open + 1..close]);
+                    }
+                    _ => return Err(Error::Parse("invalid block name".to_string())),
+                }
+            }
+            rest = &rest[close + 1..];
+        };
+
+        let mut members = Vec::new();
+        let mut offset = 0;
+        for decl in body.split(';').map(str::trim).filter(|d| !d.is_empty()) {
+            for member in parse_declaration(decl)? {
+                // Array elements are rounded up to the alignment of
+                // vec4.
+                let (align, size) = match member.array_len {
+                    Some(len) => (
+                        round_up(member.typ.align(), 16),
+                        round_up(member.typ.size(), 16)
+                            .checked_mul(len)
+                            .ok_or_else(|| {
+                                Error::Parse(format!("array too large: {}", member.name))
+                            })?,
+                    ),
+                    None => (member.typ.align(), member.typ.size()),
+                };
+                offset = round_up(offset, align);
+                members.push(Member {
+                    offset,
+                    size,
+                    ..member
+                });
+                offset = offset
+                    .checked_add(size)
+                    .ok_or_else(|| Error::Parse("block too large".to_string()))?;
+            }
+        }
+
+        Ok(UniformBlock {
+            name: name.to_string(),
+            members,
+            size: round_up(offset, 16),
+        })
+    }
+
+    /// Returns the member `name`.
+    pub fn member(&self, name: &str) -> Option<&Member> {
+        self.members.iter().find(|m| m.name == name)
+    }
+
+    /// Emits the source code of a `#[repr(C)]` Rust struct with the
+    /// same layout as the uniform block. Explicit padding fields are
+    /// inserted where required. [`Default`] is implemented manually,
+    /// so arrays of any length are supported.
+    pub fn to_rust_struct(&self) -> String {
+        let mut fields = Vec::new();
+        let mut zeros = Vec::new();
+        let mut offset = 0;
+        let mut npad = 0;
+        let mut pad =
+            |fields: &mut Vec<String>, zeros: &mut Vec<String>, from: usize, to: usize| {
+                if to > from {
+                    fields.push(format!("    pub _pad{npad}: [u8; {}],", to - from));
+                    zeros.push(format!("            _pad{npad}: [0; {}],", to - from));
+                    npad += 1;
+                }
+            };
+
+        for m in &self.members {
+            pad(&mut fields, &mut zeros, offset, m.offset);
+            let (typ, zero) = match m.array_len {
+                Some(len) => (
+                    format!("[{}; {len}]", m.typ.rust_type(true)),
+                    format!("[{}; {len}]", m.typ.rust_zero(true)),
+                ),
+                None => (m.typ.rust_type(false), m.typ.rust_zero(false)),
+            };
+            fields.push(format!("    pub {}: {typ},", m.name));
+            zeros.push(format!("            {}: {zero},", m.name));
+            offset = m.offset + m.size;
+        }
+        pad(&mut fields, &mut zeros, offset, self.size);
+
+        format!(
+            "#[derive(Clone, Copy)]\n#[repr(C)]\npub struct {name} {{\n{}\n}}\n\n\
+             impl Default for {name} {{\n    fn default() -> {name} {{\n        {name} {{\n{}\n        }}\n    }}\n}}\n",
+            fields.join("\n"),
+            zeros.join("\n"),
+            name = self.name,
+        )
+    }
+
+    /// Checks that a Rust type with the specified `size` and member
+    /// `offsets` has the same layout as the uniform block. The
+    /// offsets can be obtained with [`std::mem::offset_of`].
+    pub fn validate(&self, size: usize, offsets: &[(&str, usize)]) -> Result<()> {
+        for (name, offset) in offsets {
+            let member = self
+                .member(name)
+                .ok_or_else(|| Error::UnknownMember(name.to_string()))?;
+            if member.offset != *offset {
+                return Err(Error::LayoutMismatch {
+                    member: name.to_string(),
+                    expected: member.offset,
+                    found: *offset,
+                });
+            }
+        }
+        if size != self.size {
+            return Err(Error::LayoutMismatch {
+                member: String::new(),
+                expected: self.size,
+                found: size,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Parses a member declaration like `vec4 a, b[2]`. The offset and
+/// size of the returned members are not computed.
+fn parse_declaration(decl: &str) -> Result<Vec<Member>> {
+    let mut words = decl.split_whitespace().peekable();
+
+    while let Some(word) = words.peek() {
+        match *word {
+            "highp" | "mediump" | "lowp" => {
+                words.next();
+            }
+            w if w.starts_with("layout") || w == "row_major" => {
+                return Err(Error::Unsupported(decl.to_string()));
+            }
+            _ => break,
+        }
+    }
+
+    let typ = words.next().ok_or_else(|| Error::Parse(decl.to_string()))?;
+    let typ = Type::parse(typ).ok_or_else(|| Error::Unsupported(decl.to_string()))?;
+
+    let names = words.collect::<Vec<_>>().join("");
+    names
+        .split(',')
+        .map(|name| {
+            let (name, array_len) = match name.split_once('[') {
+                Some((name, len)) => {
+                    let len = len
+                        .strip_suffix(']')
+                        .and_then(|len| len.parse::<usize>().ok())
+                        .ok_or_else(|| Error::Parse(decl.to_string()))?;
+                    (name, Some(len))
+                }
+                None => (name, None),
+            };
+            if !is_identifier(name) {
+                return Err(Error::Parse(decl.to_string()));
+            }
+            Ok(Member {
+                name: name.to_string(),
+                typ,
+                array_len,
+                offset: 0,
+                size: 0,
+            })
+        })
+        .collect()
+}
+
+/// Removes line and block comments.
+fn strip_comments(src: &str) -> String {
+    let mut out = String::with_capacity(src.len());
+    let mut rest = src;
+    loop {
+        let line = rest.find("//");
+        let block = rest.find("/*");
+        let (pos, end) = match (line, block) {
+            (Some(l), Some(b)) if l < b => (l, "\n"),
+            (Some(l), None) => (l, "\n"),
+            (_, Some(b)) => (b, "*/"),
+            (None, None) => break,
+        };
+        out.push_str(&rest[..pos]);
+        out.push(' ');
+        rest = match rest[pos + 2..].find(end) {
+            Some(i) => &rest[pos + 2 + i + end.len()..],
+            None => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Reports whether `s` is a valid GLSL identifier.
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Rounds `n` up to the next multiple of `align`.
+fn round_up(n: usize, align: usize) -> usize {
+    n.div_ceil(align) * align
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offsets(block: &UniformBlock) -> Vec<(&str, usize, usize)> {
+        block
+            .members
+            .iter()
+            .map(|m| (m.name.as_str(), m.offset, m.size))
+            .collect()
+    }
+
+    #[test]
+    fn vec3_followed_by_float() {
+        let block = UniformBlock::parse("uniform Block { vec3 a; float b; vec3 c; };").unwrap();
+        assert_eq!(block.name, "Block");
+        assert_eq!(offsets(&block), [("a", 0, 12), ("b", 12, 4), ("c", 16, 12)]);
+        assert_eq!(block.size, 32);
+    }
+
+    #[test]
+    fn arrays_and_matrices() {
+        let block = UniformBlock::parse(
+            "uniform Block {
+                float a[3];
+                vec2 b;
+                mat3 c;
+                vec2 d[2], e;
+                mat4 f;
+            };",
+        )
+        .unwrap();
+        assert_eq!(
+            offsets(&block),
+            [
+                ("a", 0, 48),
+                ("b", 48, 8),
+                ("c", 64, 48),
+                ("d", 112, 32),
+                ("e", 144, 8),
+                ("f", 160, 64),
+            ]
+        );
+        assert_eq!(block.size, 224);
+    }
+
+    #[test]
+    fn layout_qualifiers() {
+        let src = "#version 420 core
+            layout(std140, binding = 2) uniform Camera {
+                mat4 view; // view matrix
+                mat4 proj; /* projection matrix */
+            } camera;";
+        let block = UniformBlock::parse(src).unwrap();
+        assert_eq!(block.name, "Camera");
+        assert_eq!(offsets(&block), [("view", 0, 64), ("proj", 64, 64)]);
+        assert_eq!(block.size, 128);
+    }
+
+    #[test]
+    fn block_after_struct_and_function() {
+        let src = "struct Light { vec3 pos; float radius; };
+            void f() { if (true) { return; } }
+            layout(std140) uniform Lights { int count; };";
+        let block = UniformBlock::parse(src).unwrap();
+        assert_eq!(block.name, "Lights");
+        assert_eq!(offsets(&block), [("count", 0, 4)]);
+        assert_eq!(block.size, 16);
+    }
+
+    #[test]
+    fn missing_block() {
+        let src = "struct Light { vec3 pos; };";
+        assert!(matches!(
+            UniformBlock::parse(src),
+            Err(Error::MissingUniformBlock)
+        ));
+    }
+
+    #[test]
+    fn rust_struct_padding() {
+        let block = UniformBlock::parse("uniform Block { float a; vec3 b; float c[2]; };").unwrap();
+        assert_eq!(
+            block.to_rust_struct(),
+            "#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Block {
+    pub a: f32,
+    pub _pad0: [u8; 12],
+    pub b: [f32; 3],
+    pub _pad1: [u8; 4],
+    pub c: [[f32; 4]; 2],
+}
+
+impl Default for Block {
+    fn default() -> Block {
+        Block {
+            a: 0.0,
+            _pad0: [0; 12],
+            b: [0.0; 3],
+            _pad1: [0; 4],
+            c: [[0.0; 4]; 2],
+        }
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn rust_struct_large_arrays() {
+        let block = UniformBlock::parse(
+            "uniform Bones { mat4 bones[100]; vec4 lights[64]; ivec2 ids[40]; uint n; };",
+        )
+        .unwrap();
+        assert_eq!(block.size, 100 * 64 + 64 * 16 + 40 * 16 + 16);
+
+        let dir = std::env::temp_dir().join(format!("hitchcock-glsl-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let src = dir.join("bones.rs");
+        std::fs::write(
+            &src,
+            format!(
+                "{}\npub fn zero() -> Bones {{ Bones::default() }}\n",
+                block.to_rust_struct()
+            ),
+        )
+        .unwrap();
+        let status = std::process::Command::new(std::env::var("RUSTC").unwrap_or("rustc".into()))
+            .args([
+                "--edition",
+                "2021",
+                "--crate-type",
+                "lib",
+                "--emit",
+                "metadata",
+            ])
+            .arg("--out-dir")
+            .arg(&dir)
+            .arg(&src)
+            .status()
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    fn validate_layout() {
+        let block = UniformBlock::parse("uniform Block { vec3 a; float b; };").unwrap();
+        assert!(block.validate(16, &[("a", 0), ("b", 12)]).is_ok());
+        assert!(matches!(
+            block.validate(16, &[("b", 16)]),
+            Err(Error::LayoutMismatch { expected: 12, .. })
+        ));
+        assert!(matches!(
+            block.validate(16, &[("x", 0)]),
+            Err(Error::UnknownMember(_))
+        ));
+    }
+}
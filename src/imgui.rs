@@ -1,12 +1,19 @@
 //! Dear ImGui bindings.
 
 use std::{
+    collections::BTreeMap,
     error,
-    ffi::{c_uchar, CString, NulError},
-    fmt, ptr, result,
+    ffi::{c_char, c_int, c_uchar, c_void, CString, NulError},
+    fmt, mem, ptr, result, slice,
+    sync::Mutex,
 };
 
-use crate::{macros::define_opaque, Vec2, Vec4};
+use crate::{
+    curve::{Curve, CurvePoint},
+    gl,
+    macros::define_opaque,
+    Vec2, Vec3, Vec4,
+};
 
 #[allow(
     non_upper_case_globals,
@@ -15,22 +22,50 @@ use crate::{macros::define_opaque, Vec2, Vec4};
     dead_code
 )]
 mod ffi {
-    use std::ffi::{c_char, c_double, c_float, c_int, c_schar, c_uchar, c_uint, c_ushort, c_void};
+    use std::ffi::{
+        c_char, c_double, c_float, c_int, c_schar, c_short, c_uchar, c_uint, c_ulonglong, c_ushort,
+        c_void,
+    };
 
-    use crate::Vec2;
+    use crate::{Vec2, Vec4};
 
     pub type ImGuiBackendFlags = c_int;
+    pub type ImGuiButtonFlags = c_int;
+    pub type ImGuiChildFlags = c_int;
     pub type ImGuiCond = c_int;
     pub type ImGuiColorEditFlags = c_int;
+    pub type ImGuiComboFlags = c_int;
     pub type ImGuiConfigFlags = c_int;
+    pub type ImGuiDataType = c_int;
+    pub type ImGuiDir = c_int;
+    pub type ImGuiHoveredFlags = c_int;
     pub type ImGuiID = c_uint;
+    pub type ImGuiInputTextCallback =
+        Option<unsafe extern "C" fn(data: *mut ImGuiInputTextCallbackData) -> c_int>;
+    pub type ImGuiInputTextFlags = c_int;
+    pub type ImGuiKey = c_int;
     pub type ImGuiKeyChord = c_int;
     pub type ImGuiMouseSource = c_int;
+    pub type ImGuiPopupFlags = c_int;
+    pub type ImGuiSelectableFlags = c_int;
     pub type ImGuiSliderFlags = c_int;
+    pub type ImGuiSortDirection = ImU8;
+    pub type ImGuiTabBarFlags = c_int;
+    pub type ImGuiTabItemFlags = c_int;
+    pub type ImGuiTableColumnFlags = c_int;
+    pub type ImGuiTableFlags = c_int;
+    pub type ImGuiTableRowFlags = c_int;
+    pub type ImGuiTreeNodeFlags = c_int;
     pub type ImGuiViewportFlags = c_int;
     pub type ImGuiWindowFlags = c_int;
+    pub type ImDrawFlags = c_int;
+    pub type ImS16 = c_short;
     pub type ImS8 = c_schar;
+    pub type ImTextureID = ImU64;
     pub type ImU16 = c_ushort;
+    pub type ImU32 = c_uint;
+    pub type ImU64 = c_ulonglong;
+    pub type ImU8 = c_uchar;
     pub type ImWchar = ImWchar16;
     pub type ImWchar16 = c_ushort;
 
@@ -155,6 +190,24 @@ mod ffi {
         pub InputQueueCharacters: ImVector_ImWchar,
     }
 
+    #[repr(C)]
+    pub struct ImGuiInputTextCallbackData {
+        // TODO: replace with `*mut ImGuiContext`.
+        pub Ctx: *mut c_void,
+        pub EventFlag: ImGuiInputTextFlags,
+        pub Flags: ImGuiInputTextFlags,
+        pub UserData: *mut c_void,
+        pub EventChar: ImWchar,
+        pub EventKey: ImGuiKey,
+        pub Buf: *mut c_char,
+        pub BufTextLen: c_int,
+        pub BufSize: c_int,
+        pub BufDirty: c_uchar,
+        pub CursorPos: c_int,
+        pub SelectionStart: c_int,
+        pub SelectionEnd: c_int,
+    }
+
     #[repr(C)]
     pub struct ImGuiKeyData {
         pub Down: c_uchar,
@@ -163,6 +216,22 @@ mod ffi {
         pub AnalogValue: c_float,
     }
 
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct ImGuiTableColumnSortSpecs {
+        pub ColumnUserID: ImGuiID,
+        pub ColumnIndex: ImS16,
+        pub SortOrder: ImS16,
+        pub SortDirection: ImGuiSortDirection,
+    }
+
+    #[repr(C)]
+    pub struct ImGuiTableSortSpecs {
+        pub Specs: *const ImGuiTableColumnSortSpecs,
+        pub SpecsCount: c_int,
+        pub SpecsDirty: c_uchar,
+    }
+
     #[repr(C)]
     pub struct ImGuiViewport {
         pub ID: ImGuiID,
@@ -201,6 +270,16 @@ mod ffi {
         }
     }
 
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    pub struct ImVec4([c_float; 4]);
+
+    impl From<Vec4<f32>> for ImVec4 {
+        fn from(v: Vec4<f32>) -> ImVec4 {
+            ImVec4(v.0)
+        }
+    }
+
     #[repr(C)]
     pub struct ImVector_ImWchar {
         pub Size: c_int,
@@ -209,29 +288,383 @@ mod ffi {
     }
 
     extern "C" {
+        pub fn ImDrawList_AddCircleFilled(
+            this: *mut c_void,
+            center: ImVec2,
+            radius: c_float,
+            col: ImU32,
+            num_segments: c_int,
+        );
+        pub fn ImDrawList_AddLine(
+            this: *mut c_void,
+            p1: ImVec2,
+            p2: ImVec2,
+            col: ImU32,
+            thickness: c_float,
+        );
+        pub fn ImDrawList_AddRect(
+            this: *mut c_void,
+            p_min: ImVec2,
+            p_max: ImVec2,
+            col: ImU32,
+            rounding: c_float,
+            flags: ImDrawFlags,
+            thickness: c_float,
+        );
+        pub fn ImDrawList_AddRectFilled(
+            this: *mut c_void,
+            p_min: ImVec2,
+            p_max: ImVec2,
+            col: ImU32,
+            rounding: c_float,
+            flags: ImDrawFlags,
+        );
+        pub fn ImGuiIO_AddInputCharacter(this: *mut ImGuiIO, c: c_uint);
+        pub fn ImGuiIO_AddInputCharactersUTF8(this: *mut ImGuiIO, str: *const c_char);
+        pub fn ImGuiInputTextCallbackData_DeleteChars(
+            this: *mut ImGuiInputTextCallbackData,
+            pos: c_int,
+            bytes_count: c_int,
+        );
+        pub fn ImGuiInputTextCallbackData_InsertChars(
+            this: *mut ImGuiInputTextCallbackData,
+            pos: c_int,
+            text: *const c_char,
+            text_end: *const c_char,
+        );
+        pub fn igAlignTextToFramePadding();
+        pub fn igArrowButton(str_id: *const c_char, dir: ImGuiDir) -> c_uchar;
         pub fn igBegin(
             name: *const c_char,
             p_open: *mut c_uchar,
             flags: ImGuiWindowFlags,
         ) -> c_uchar;
+        pub fn igBeginChild_Str(
+            str_id: *const c_char,
+            size: ImVec2,
+            child_flags: ImGuiChildFlags,
+            window_flags: ImGuiWindowFlags,
+        ) -> c_uchar;
+        pub fn igBeginCombo(
+            label: *const c_char,
+            preview_value: *const c_char,
+            flags: ImGuiComboFlags,
+        ) -> c_uchar;
+        pub fn igBeginGroup();
+        pub fn igBeginListBox(label: *const c_char, size: ImVec2) -> c_uchar;
+        pub fn igBeginMainMenuBar() -> c_uchar;
+        pub fn igBeginMenu(label: *const c_char, enabled: c_uchar) -> c_uchar;
+        pub fn igBeginMenuBar() -> c_uchar;
+        pub fn igBeginPopup(str_id: *const c_char, flags: ImGuiWindowFlags) -> c_uchar;
+        pub fn igBeginPopupContextItem(
+            str_id: *const c_char,
+            popup_flags: ImGuiPopupFlags,
+        ) -> c_uchar;
+        pub fn igBeginPopupContextWindow(
+            str_id: *const c_char,
+            popup_flags: ImGuiPopupFlags,
+        ) -> c_uchar;
+        pub fn igBeginPopupModal(
+            name: *const c_char,
+            p_open: *mut c_uchar,
+            flags: ImGuiWindowFlags,
+        ) -> c_uchar;
+        pub fn igBeginTabBar(str_id: *const c_char, flags: ImGuiTabBarFlags) -> c_uchar;
+        pub fn igBeginTabItem(
+            label: *const c_char,
+            p_open: *mut c_uchar,
+            flags: ImGuiTabItemFlags,
+        ) -> c_uchar;
+        pub fn igBeginTable(
+            str_id: *const c_char,
+            column: c_int,
+            flags: ImGuiTableFlags,
+            outer_size: ImVec2,
+            inner_width: c_float,
+        ) -> c_uchar;
+        pub fn igBeginTooltip() -> c_uchar;
+        pub fn igBulletText(fmt: *const c_char, ...);
+        pub fn igButton(label: *const c_char, size: ImVec2) -> c_uchar;
+        pub fn igCalcItemWidth() -> c_float;
         pub fn igCheckbox(label: *const c_char, v: *mut c_uchar) -> c_uchar;
+        pub fn igCloseCurrentPopup();
+        pub fn igCollapsingHeader_BoolPtr(
+            label: *const c_char,
+            p_visible: *mut c_uchar,
+            flags: ImGuiTreeNodeFlags,
+        ) -> c_uchar;
+        pub fn igCollapsingHeader_TreeNodeFlags(
+            label: *const c_char,
+            flags: ImGuiTreeNodeFlags,
+        ) -> c_uchar;
+        pub fn igColorButton(
+            desc_id: *const c_char,
+            col: ImVec4,
+            flags: ImGuiColorEditFlags,
+            size: ImVec2,
+        ) -> c_uchar;
+        pub fn igColorEdit3(
+            label: *const c_char,
+            col: *mut c_float,
+            flags: ImGuiColorEditFlags,
+        ) -> c_uchar;
         pub fn igColorEdit4(
             label: *const c_char,
             col: *mut c_float,
             flags: ImGuiColorEditFlags,
         ) -> c_uchar;
+        pub fn igColorPicker3(
+            label: *const c_char,
+            col: *mut c_float,
+            flags: ImGuiColorEditFlags,
+        ) -> c_uchar;
+        pub fn igColorPicker4(
+            label: *const c_char,
+            col: *mut c_float,
+            flags: ImGuiColorEditFlags,
+            ref_col: *const c_float,
+        ) -> c_uchar;
+        pub fn igCombo_Str_arr(
+            label: *const c_char,
+            current_item: *mut c_int,
+            items: *const *const c_char,
+            items_count: c_int,
+            popup_max_height_in_items: c_int,
+        ) -> c_uchar;
         pub fn igCreateContext(shared_font_atlas: *mut c_void) -> *mut c_void;
         pub fn igDestroyContext(ctx: *mut c_void);
+        pub fn igDragFloat(
+            label: *const c_char,
+            v: *mut c_float,
+            v_speed: c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igDragFloat2(
+            label: *const c_char,
+            v: *mut c_float,
+            v_speed: c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igDragFloat3(
+            label: *const c_char,
+            v: *mut c_float,
+            v_speed: c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igDragFloat4(
+            label: *const c_char,
+            v: *mut c_float,
+            v_speed: c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igDragFloatRange2(
+            label: *const c_char,
+            v_current_min: *mut c_float,
+            v_current_max: *mut c_float,
+            v_speed: c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            format_max: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igDragInt(
+            label: *const c_char,
+            v: *mut c_int,
+            v_speed: c_float,
+            v_min: c_int,
+            v_max: c_int,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igDummy(size: ImVec2);
         pub fn igEnd();
+        pub fn igEndChild();
+        pub fn igEndCombo();
+        pub fn igEndGroup();
+        pub fn igEndListBox();
+        pub fn igEndMainMenuBar();
+        pub fn igEndMenu();
+        pub fn igEndMenuBar();
+        pub fn igEndPopup();
+        pub fn igEndTabBar();
+        pub fn igEndTabItem();
+        pub fn igEndTable();
+        pub fn igEndTooltip();
+        pub fn igGetCursorScreenPos(p_out: *mut ImVec2);
         pub fn igGetDrawData() -> *mut c_void;
         pub fn igGetIO() -> *mut ImGuiIO;
+        pub fn igGetItemID() -> ImGuiID;
         pub fn igGetMainViewport() -> *mut ImGuiViewport;
+        pub fn igGetWindowDrawList() -> *mut c_void;
+        pub fn igImage(
+            user_texture_id: ImTextureID,
+            image_size: ImVec2,
+            uv0: ImVec2,
+            uv1: ImVec2,
+            tint_col: ImVec4,
+            border_col: ImVec4,
+        );
+        pub fn igImageButton(
+            str_id: *const c_char,
+            user_texture_id: ImTextureID,
+            image_size: ImVec2,
+            uv0: ImVec2,
+            uv1: ImVec2,
+            bg_col: ImVec4,
+            tint_col: ImVec4,
+        ) -> c_uchar;
+        pub fn igIndent(indent_w: c_float);
+        pub fn igInputDouble(
+            label: *const c_char,
+            v: *mut c_double,
+            step: c_double,
+            step_fast: c_double,
+            format: *const c_char,
+            flags: ImGuiInputTextFlags,
+        ) -> c_uchar;
+        pub fn igInputFloat(
+            label: *const c_char,
+            v: *mut c_float,
+            step: c_float,
+            step_fast: c_float,
+            format: *const c_char,
+            flags: ImGuiInputTextFlags,
+        ) -> c_uchar;
+        pub fn igInputFloat3(
+            label: *const c_char,
+            v: *mut c_float,
+            format: *const c_char,
+            flags: ImGuiInputTextFlags,
+        ) -> c_uchar;
+        pub fn igInputInt(
+            label: *const c_char,
+            v: *mut c_int,
+            step: c_int,
+            step_fast: c_int,
+            flags: ImGuiInputTextFlags,
+        ) -> c_uchar;
+        pub fn igInputScalarN(
+            label: *const c_char,
+            data_type: ImGuiDataType,
+            p_data: *mut c_void,
+            components: c_int,
+            p_step: *const c_void,
+            p_step_fast: *const c_void,
+            format: *const c_char,
+            flags: ImGuiInputTextFlags,
+        ) -> c_uchar;
+        pub fn igInputText(
+            label: *const c_char,
+            buf: *mut c_char,
+            buf_size: usize,
+            flags: ImGuiInputTextFlags,
+            callback: ImGuiInputTextCallback,
+            user_data: *mut c_void,
+        ) -> c_uchar;
+        pub fn igInputTextMultiline(
+            label: *const c_char,
+            buf: *mut c_char,
+            buf_size: usize,
+            size: ImVec2,
+            flags: ImGuiInputTextFlags,
+            callback: ImGuiInputTextCallback,
+            user_data: *mut c_void,
+        ) -> c_uchar;
+        pub fn igInvisibleButton(
+            str_id: *const c_char,
+            size: ImVec2,
+            flags: ImGuiButtonFlags,
+        ) -> c_uchar;
+        pub fn igIsItemActive() -> c_uchar;
+        pub fn igIsItemHovered(flags: ImGuiHoveredFlags) -> c_uchar;
+        pub fn igLabelText(label: *const c_char, fmt: *const c_char, ...);
+        pub fn igLoadIniSettingsFromMemory(ini_data: *const c_char, ini_size: usize);
+        pub fn igMenuItem_Bool(
+            label: *const c_char,
+            shortcut: *const c_char,
+            selected: c_uchar,
+            enabled: c_uchar,
+        ) -> c_uchar;
+        pub fn igMenuItem_BoolPtr(
+            label: *const c_char,
+            shortcut: *const c_char,
+            p_selected: *mut c_uchar,
+            enabled: c_uchar,
+        ) -> c_uchar;
         pub fn igNewFrame();
+        pub fn igNewLine();
+        pub fn igOpenPopup_Str(str_id: *const c_char, popup_flags: ImGuiPopupFlags);
+        pub fn igPlotHistogram_FloatPtr(
+            label: *const c_char,
+            values: *const c_float,
+            values_count: c_int,
+            values_offset: c_int,
+            overlay_text: *const c_char,
+            scale_min: c_float,
+            scale_max: c_float,
+            graph_size: ImVec2,
+            stride: c_int,
+        );
+        pub fn igPlotLines_FloatPtr(
+            label: *const c_char,
+            values: *const c_float,
+            values_count: c_int,
+            values_offset: c_int,
+            overlay_text: *const c_char,
+            scale_min: c_float,
+            scale_max: c_float,
+            graph_size: ImVec2,
+            stride: c_int,
+        );
+        pub fn igPopItemWidth();
+        pub fn igProgressBar(fraction: c_float, size_arg: ImVec2, overlay: *const c_char);
+        pub fn igPushItemWidth(item_width: c_float);
+        pub fn igRadioButton_Bool(label: *const c_char, active: c_uchar) -> c_uchar;
+        pub fn igRadioButton_IntPtr(
+            label: *const c_char,
+            v: *mut c_int,
+            v_button: c_int,
+        ) -> c_uchar;
         pub fn igRender();
         pub fn igSameLine(offset_from_start_x: c_float, spacing: c_float);
+        pub fn igSaveIniSettingsToMemory(out_ini_size: *mut usize) -> *const c_char;
+        pub fn igSelectable_Bool(
+            label: *const c_char,
+            selected: c_uchar,
+            flags: ImGuiSelectableFlags,
+            size: ImVec2,
+        ) -> c_uchar;
+        pub fn igSeparator();
+        pub fn igSeparatorText(label: *const c_char);
+        pub fn igSetItemDefaultFocus();
+        pub fn igSetItemTooltip(fmt: *const c_char, ...);
+        pub fn igSetNextItemOpen(is_open: c_uchar, cond: ImGuiCond);
+        pub fn igSetNextItemWidth(item_width: c_float);
         pub fn igSetNextWindowPos(pos: ImVec2, cond: ImGuiCond, pivot: ImVec2);
         pub fn igSetNextWindowSize(size: ImVec2, cond: ImGuiCond);
+        pub fn igSetTooltip(fmt: *const c_char, ...);
         pub fn igShowDemoWindow(p_open: *mut c_uchar);
+        pub fn igSliderAngle(
+            label: *const c_char,
+            v_rad: *mut c_float,
+            v_degrees_min: c_float,
+            v_degrees_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
         pub fn igSliderFloat(
             label: *const c_char,
             v: *mut c_float,
@@ -240,22 +673,315 @@ mod ffi {
             format: *const c_char,
             flags: ImGuiSliderFlags,
         ) -> c_uchar;
+        pub fn igSliderFloat2(
+            label: *const c_char,
+            v: *mut c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igSliderFloat3(
+            label: *const c_char,
+            v: *mut c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igSliderFloat4(
+            label: *const c_char,
+            v: *mut c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igSliderInt(
+            label: *const c_char,
+            v: *mut c_int,
+            v_min: c_int,
+            v_max: c_int,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
+        pub fn igSmallButton(label: *const c_char) -> c_uchar;
+        pub fn igSpacing();
+        pub fn igTabItemButton(label: *const c_char, flags: ImGuiTabItemFlags) -> c_uchar;
+        pub fn igTableGetSortSpecs() -> *mut ImGuiTableSortSpecs;
+        pub fn igTableHeadersRow();
+        pub fn igTableNextColumn() -> c_uchar;
+        pub fn igTableNextRow(row_flags: ImGuiTableRowFlags, min_row_height: c_float);
+        pub fn igTableSetColumnIndex(column_n: c_int) -> c_uchar;
+        pub fn igTableSetupColumn(
+            label: *const c_char,
+            flags: ImGuiTableColumnFlags,
+            init_width_or_weight: c_float,
+            user_id: ImGuiID,
+        );
+        pub fn igTableSetupScrollFreeze(cols: c_int, rows: c_int);
         pub fn igText(fmt: *const c_char, ...);
+        pub fn igTextColored(col: ImVec4, fmt: *const c_char, ...);
+        pub fn igTextDisabled(fmt: *const c_char, ...);
+        pub fn igTextWrapped(fmt: *const c_char, ...);
+        pub fn igTreeNodeEx_Str(label: *const c_char, flags: ImGuiTreeNodeFlags) -> c_uchar;
+        pub fn igTreeNode_Str(label: *const c_char) -> c_uchar;
+        pub fn igTreePop();
+        pub fn igUnindent(indent_w: c_float);
+        pub fn igVSliderFloat(
+            label: *const c_char,
+            size: ImVec2,
+            v: *mut c_float,
+            v_min: c_float,
+            v_max: c_float,
+            format: *const c_char,
+            flags: ImGuiSliderFlags,
+        ) -> c_uchar;
     }
 }
 
+/// Show an outer border and enable window padding.
+pub const CHILD_FLAGS_BORDERS: i32 = 1 << 0;
+
+/// Pad with the window padding even if no border is drawn.
+pub const CHILD_FLAGS_ALWAYS_USE_WINDOW_PADDING: i32 = 1 << 1;
+
+/// Allow resize from right border.
+pub const CHILD_FLAGS_RESIZE_X: i32 = 1 << 2;
+
+/// Allow resize from bottom border.
+pub const CHILD_FLAGS_RESIZE_Y: i32 = 1 << 3;
+
+/// Enable auto-resizing width.
+pub const CHILD_FLAGS_AUTO_RESIZE_X: i32 = 1 << 4;
+
+/// Enable auto-resizing height.
+pub const CHILD_FLAGS_AUTO_RESIZE_Y: i32 = 1 << 5;
+
+/// Ignore the alpha component.
+pub const COLOR_EDIT_FLAGS_NO_ALPHA: i32 = 1 << 1;
+
+/// Do not open the color picker when clicking on the color square.
+pub const COLOR_EDIT_FLAGS_NO_PICKER: i32 = 1 << 2;
+
 /// Do not show input fields in color picker widget.
 pub const COLOR_EDIT_FLAGS_NO_INPUTS: i32 = 1 << 5;
 
+/// Do not show the bigger color preview on the right side of the
+/// picker.
+pub const COLOR_EDIT_FLAGS_NO_SIDE_PREVIEW: i32 = 1 << 8;
+
+/// Show vertical alpha bar in the picker.
+pub const COLOR_EDIT_FLAGS_ALPHA_BAR: i32 = 1 << 16;
+
+/// Display a checkerboard behind transparent colors.
+pub const COLOR_EDIT_FLAGS_ALPHA_PREVIEW: i32 = 1 << 17;
+
+/// Display RGB inputs.
+pub const COLOR_EDIT_FLAGS_DISPLAY_RGB: i32 = 1 << 20;
+
+/// Display HSV inputs.
+pub const COLOR_EDIT_FLAGS_DISPLAY_HSV: i32 = 1 << 21;
+
+/// Display hexadecimal input.
+pub const COLOR_EDIT_FLAGS_DISPLAY_HEX: i32 = 1 << 22;
+
+/// Use a bar for hue and a rectangle for saturation and value in the
+/// picker.
+pub const COLOR_EDIT_FLAGS_PICKER_HUE_BAR: i32 = 1 << 25;
+
+/// Use a wheel for hue and a triangle for saturation and value in
+/// the picker.
+pub const COLOR_EDIT_FLAGS_PICKER_HUE_WHEEL: i32 = 1 << 26;
+
 /// Enable keyboard controls.
 pub const CONFIG_FLAGS_NAV_ENABLE_KEYBOARD: i32 = 1 << 0;
 
 /// Enable docking mode.
 pub const CONFIG_FLAGS_DOCKING_ENABLE: i32 = 1 << 7;
 
+/// Max ~20 items visible in the combo popup.
+pub const COMBO_FLAGS_HEIGHT_LARGE: i32 = 1 << 3;
+
+/// Display on the preview box without the square arrow button.
+pub const COMBO_FLAGS_NO_ARROW_BUTTON: i32 = 1 << 5;
+
+/// Display only a square arrow button.
+pub const COMBO_FLAGS_NO_PREVIEW: i32 = 1 << 6;
+
+/// Allow 0123456789.+-*/ only.
+pub const INPUT_TEXT_FLAGS_CHARS_DECIMAL: i32 = 1 << 0;
+
+/// Filter out spaces and tabs.
+pub const INPUT_TEXT_FLAGS_CHARS_NO_BLANK: i32 = 1 << 4;
+
+/// Pressing Tab inputs a '\t' character into the text field.
+pub const INPUT_TEXT_FLAGS_ALLOW_TAB_INPUT: i32 = 1 << 5;
+
+/// Return true when Enter is pressed, instead of every time the value
+/// is modified.
+pub const INPUT_TEXT_FLAGS_ENTER_RETURNS_TRUE: i32 = 1 << 6;
+
+/// In multi-line mode, validate with Enter and add new lines with
+/// Ctrl+Enter.
+pub const INPUT_TEXT_FLAGS_CTRL_ENTER_FOR_NEW_LINE: i32 = 1 << 8;
+
+/// Read-only mode.
+pub const INPUT_TEXT_FLAGS_READ_ONLY: i32 = 1 << 9;
+
+/// Password mode, display all characters as '*'.
+pub const INPUT_TEXT_FLAGS_PASSWORD: i32 = 1 << 10;
+
+/// Select entire text when first taking mouse focus.
+pub const INPUT_TEXT_FLAGS_AUTO_SELECT_ALL: i32 = 1 << 12;
+
+/// Callback on pressing Tab.
+pub const INPUT_TEXT_FLAGS_CALLBACK_COMPLETION: i32 = 1 << 18;
+
+/// Callback on pressing Up or Down arrows.
+pub const INPUT_TEXT_FLAGS_CALLBACK_HISTORY: i32 = 1 << 19;
+
+/// Callback on each iteration.
+pub const INPUT_TEXT_FLAGS_CALLBACK_ALWAYS: i32 = 1 << 20;
+
+/// Callback on character inputs to replace or discard them.
+pub const INPUT_TEXT_FLAGS_CALLBACK_CHAR_FILTER: i32 = 1 << 21;
+
+/// Callback on buffer capacity change requests.
+const INPUT_TEXT_FLAGS_CALLBACK_RESIZE: i32 = 1 << 22;
+
+/// Callback on any edit.
+pub const INPUT_TEXT_FLAGS_CALLBACK_EDIT: i32 = 1 << 23;
+
+/// Open context popups on left mouse button click.
+pub const POPUP_FLAGS_MOUSE_BUTTON_LEFT: i32 = 0;
+
+/// Open context popups on right mouse button click. This is the
+/// default.
+pub const POPUP_FLAGS_MOUSE_BUTTON_RIGHT: i32 = 1;
+
+/// Open context popups on middle mouse button click.
+pub const POPUP_FLAGS_MOUSE_BUTTON_MIDDLE: i32 = 2;
+
+/// Do not open if there is already a popup at the same level of the
+/// popup stack.
+pub const POPUP_FLAGS_NO_OPEN_OVER_EXISTING_POPUP: i32 = 1 << 7;
+
+/// Clicking this item does not close the parent popup.
+pub const SELECTABLE_FLAGS_DONT_CLOSE_POPUPS: i32 = 1 << 0;
+
+/// Frame spans all columns of its container table.
+pub const SELECTABLE_FLAGS_SPAN_ALL_COLUMNS: i32 = 1 << 1;
+
+/// Generate press events on double clicks too.
+pub const SELECTABLE_FLAGS_ALLOW_DOUBLE_CLICK: i32 = 1 << 2;
+
+/// Cannot be selected, display grayed out text.
+pub const SELECTABLE_FLAGS_DISABLED: i32 = 1 << 3;
+
+/// Default as a hidden/disabled column.
+pub const TABLE_COLUMN_FLAGS_DEFAULT_HIDE: i32 = 1 << 1;
+
+/// Default as a sorting column.
+pub const TABLE_COLUMN_FLAGS_DEFAULT_SORT: i32 = 1 << 2;
+
+/// Column will stretch.
+pub const TABLE_COLUMN_FLAGS_WIDTH_STRETCH: i32 = 1 << 3;
+
+/// Column will not stretch.
+pub const TABLE_COLUMN_FLAGS_WIDTH_FIXED: i32 = 1 << 4;
+
+/// Disable manual resizing.
+pub const TABLE_COLUMN_FLAGS_NO_RESIZE: i32 = 1 << 5;
+
+/// Disable ability to hide/disable this column.
+pub const TABLE_COLUMN_FLAGS_NO_HIDE: i32 = 1 << 7;
+
+/// Disable ability to sort on this column.
+pub const TABLE_COLUMN_FLAGS_NO_SORT: i32 = 1 << 9;
+
+/// Enable resizing columns.
+pub const TABLE_FLAGS_RESIZABLE: i32 = 1 << 0;
+
+/// Enable reordering columns in header row.
+pub const TABLE_FLAGS_REORDERABLE: i32 = 1 << 1;
+
+/// Enable hiding/disabling columns in context menu.
+pub const TABLE_FLAGS_HIDEABLE: i32 = 1 << 2;
+
+/// Enable sorting. See [`table_get_sort_specs`].
+pub const TABLE_FLAGS_SORTABLE: i32 = 1 << 3;
+
+/// Use alternating background colors for rows.
+pub const TABLE_FLAGS_ROW_BG: i32 = 1 << 6;
+
+/// Draw inner and outer borders.
+pub const TABLE_FLAGS_BORDERS: i32 = 0xf << 7;
+
+/// Enable horizontal scrolling.
+pub const TABLE_FLAGS_SCROLL_X: i32 = 1 << 24;
+
+/// Enable vertical scrolling.
+pub const TABLE_FLAGS_SCROLL_Y: i32 = 1 << 25;
+
+/// Allow sorting on multiple columns by holding Shift.
+pub const TABLE_FLAGS_SORT_MULTI: i32 = 1 << 26;
+
+/// Identify header row.
+pub const TABLE_ROW_FLAGS_HEADERS: i32 = 1 << 0;
+
+/// Allow manually dragging tabs to re-order them.
+pub const TAB_BAR_FLAGS_REORDERABLE: i32 = 1 << 0;
+
+/// Automatically select new tabs when they appear.
+pub const TAB_BAR_FLAGS_AUTO_SELECT_NEW_TABS: i32 = 1 << 1;
+
+/// Show a button to open the tab list popup.
+pub const TAB_BAR_FLAGS_TAB_LIST_POPUP_BUTTON: i32 = 1 << 2;
+
+/// Display a dot next to the title and do not close the tab when
+/// middle-clicked.
+pub const TAB_ITEM_FLAGS_UNSAVED_DOCUMENT: i32 = 1 << 0;
+
+/// Make the tab selected when it is first submitted.
+pub const TAB_ITEM_FLAGS_SET_SELECTED: i32 = 1 << 1;
+
+/// Enforce the tab position to the left of the tab bar.
+pub const TAB_ITEM_FLAGS_LEADING: i32 = 1 << 6;
+
+/// Enforce the tab position to the right of the tab bar.
+pub const TAB_ITEM_FLAGS_TRAILING: i32 = 1 << 7;
+
+/// Draw as selected.
+pub const TREE_NODE_FLAGS_SELECTED: i32 = 1 << 0;
+
+/// Draw frame with background.
+pub const TREE_NODE_FLAGS_FRAMED: i32 = 1 << 1;
+
+/// Default node to be open.
+pub const TREE_NODE_FLAGS_DEFAULT_OPEN: i32 = 1 << 5;
+
+/// Open on double-click instead of simple click.
+pub const TREE_NODE_FLAGS_OPEN_ON_DOUBLE_CLICK: i32 = 1 << 6;
+
+/// Open only when clicking on the arrow part.
+pub const TREE_NODE_FLAGS_OPEN_ON_ARROW: i32 = 1 << 7;
+
+/// No collapsing, no arrow. Use as a convenience for leaf nodes.
+pub const TREE_NODE_FLAGS_LEAF: i32 = 1 << 8;
+
+/// Display a bullet instead of an arrow.
+pub const TREE_NODE_FLAGS_BULLET: i32 = 1 << 9;
+
+/// Extend hit box to the right-most edge.
+pub const TREE_NODE_FLAGS_SPAN_AVAIL_WIDTH: i32 = 1 << 11;
+
 /// Always autoresize window.
 pub const WINDOW_FLAGS_ALWAYS_AUTORESIZE: i32 = 1 << 6;
 
+/// Has a menu bar. See [`begin_menu_bar`].
+pub const WINDOW_FLAGS_MENU_BAR: i32 = 1 << 10;
+
 /// A specialized result type.
 pub type Result<T> = result::Result<T, Error>;
 
@@ -268,6 +994,9 @@ pub enum Error {
     /// Error when calling `ImGui_ImplOpenGL3_Init`.
     ImGuiImplOpenGL3Init,
 
+    /// Character not supported by Dear ImGui.
+    UnsupportedChar(char),
+
     /// Invalid C string.
     InvalidCString(NulError),
 }
@@ -285,6 +1014,7 @@ impl fmt::Display for Error {
                 write!(f, "failed to initialize ImGui GLFW backend")
             }
             Error::ImGuiImplOpenGL3Init => write!(f, "failed to initialize ImGui OpenGL backend"),
+            Error::UnsupportedChar(c) => write!(f, "unsupported character: {c:?}"),
             Error::InvalidCString(err) => write!(f, "invalid C string: {err}"),
         }
     }
@@ -298,30 +1028,321 @@ define_opaque! {
     pub opaque DrawData(mut);
 }
 
-/// Pushes a new window to the stack to start appending widgets to
-/// it. If `open` is [`Option::Some`], it shows a window-closing
-/// widget in the upper-right corner of the window, which clicking
-/// will set the boolean to false when clicked. The function returns
-/// false if the window is collapsed.
-pub fn begin(name: &str, open: Option<&mut bool>, flags: Option<i32>) -> Result<bool> {
-    let name = CString::new(name)?;
-    let flags = flags.unwrap_or(0);
+/// Scalar types supported by the generic scalar widgets.
+///
+/// # Safety
+///
+/// `DATA_TYPE` must be the `ImGuiDataType` matching the memory layout
+/// of the implementor.
+pub unsafe trait Scalar: Copy {
+    /// `ImGuiDataType` value.
+    const DATA_TYPE: i32;
+}
 
-    let unfolded = match open {
-        Some(open) => {
-            let mut copen: c_uchar = if *open { 1 } else { 0 };
-            let unfolded = unsafe { ffi::igBegin(name.as_ptr(), &mut copen, flags) };
-            *open = copen != 0;
-            unfolded
+macro_rules! impl_scalar {
+    ($($t:ty => $data_type:literal),+ $(,)?) => {
+        $(
+        unsafe impl Scalar for $t {
+            const DATA_TYPE: i32 = $data_type;
         }
-        None => unsafe { ffi::igBegin(name.as_ptr(), ptr::null_mut(), flags) },
+        )+
     };
-    Ok(unfolded != 0)
 }
 
-/// Adds a checkbox widget. `checked` reports whether the checkbox is
-/// checked. The function returns whether the checkbox has changed.
-pub fn checkbox(label: &str, checked: &mut bool) -> Result<bool> {
+impl_scalar! {
+    i8 => 0,
+    u8 => 1,
+    i16 => 2,
+    u16 => 3,
+    i32 => 4,
+    u32 => 5,
+    i64 => 6,
+    u64 => 7,
+    f32 => 8,
+    f64 => 9,
+}
+
+/// Cardinal direction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dir {
+    /// Left.
+    Left = 0,
+
+    /// Right.
+    Right = 1,
+
+    /// Up.
+    Up = 2,
+
+    /// Down.
+    Down = 3,
+}
+
+/// Sort direction of a table column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Not sorted.
+    None = 0,
+
+    /// Ascending order, e.g. 0 to 9 or A to Z.
+    Ascending = 1,
+
+    /// Descending order, e.g. 9 to 0 or Z to A.
+    Descending = 2,
+}
+
+/// Vertically aligns upcoming text to the frame padding, so it lines
+/// up with framed widgets placed on the same line.
+pub fn align_text_to_frame_padding() {
+    unsafe { ffi::igAlignTextToFramePadding() }
+}
+
+/// Adds an arrow button widget pointing to the specified direction.
+/// The function returns whether the button has been pressed.
+pub fn arrow_button(str_id: &str, dir: Dir) -> Result<bool> {
+    let str_id = CString::new(str_id)?;
+    let pressed = unsafe { ffi::igArrowButton(str_id.as_ptr(), dir as i32) };
+    Ok(pressed != 0)
+}
+
+/// Pushes a new window to the stack to start appending widgets to
+/// it. If `open` is [`Option::Some`], it shows a window-closing
+/// widget in the upper-right corner of the window, which clicking
+/// will set the boolean to false when clicked. The function returns
+/// false if the window is collapsed.
+pub fn begin(name: &str, open: Option<&mut bool>, flags: Option<i32>) -> Result<bool> {
+    let name = CString::new(name)?;
+    let flags = flags.unwrap_or(0);
+
+    let unfolded = match open {
+        Some(open) => {
+            let mut copen: c_uchar = if *open { 1 } else { 0 };
+            let unfolded = unsafe { ffi::igBegin(name.as_ptr(), &mut copen, flags) };
+            *open = copen != 0;
+            unfolded
+        }
+        None => unsafe { ffi::igBegin(name.as_ptr(), ptr::null_mut(), flags) },
+    };
+    Ok(unfolded != 0)
+}
+
+/// Begins a child window, which is a self-contained scrolling region
+/// within the current window. A zero component of `size` uses the
+/// remaining space in that direction and a negative one leaves that
+/// much space. [`end_child`] must always be called, even if the
+/// function returns false, in which case the child window is
+/// collapsed or clipped and its contents can be skipped.
+pub fn begin_child(
+    str_id: &str,
+    size: Option<Vec2<f32>>,
+    child_flags: Option<i32>,
+    window_flags: Option<i32>,
+) -> Result<bool> {
+    let str_id = CString::new(str_id)?;
+    let size = size.unwrap_or([0.0, 0.0].into());
+    let child_flags = child_flags.unwrap_or(0);
+    let window_flags = window_flags.unwrap_or(0);
+    let visible =
+        unsafe { ffi::igBeginChild_Str(str_id.as_ptr(), size.into(), child_flags, window_flags) };
+    Ok(visible != 0)
+}
+
+/// Begins a combo box. `preview_value` is displayed in the closed
+/// combo. If the function returns true, the combo popup is open and
+/// its items must be added followed by a call to [`end_combo`].
+pub fn begin_combo(label: &str, preview_value: &str, flags: Option<i32>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let preview_value = CString::new(preview_value)?;
+    let flags = flags.unwrap_or(0);
+    let open = unsafe { ffi::igBeginCombo(label.as_ptr(), preview_value.as_ptr(), flags) };
+    Ok(open != 0)
+}
+
+/// Begins a group, which lays out its items as a single item. This
+/// allows, for instance, using [`same_line`] on a group of widgets.
+/// It must be followed by a call to [`end_group`].
+pub fn begin_group() {
+    unsafe { ffi::igBeginGroup() }
+}
+
+/// Begins a list box. If `size` is [`Option::None`], the list box
+/// takes the default item width and a height of about 7 items. If the
+/// function returns true, its items must be added followed by a call
+/// to [`end_list_box`].
+pub fn begin_list_box(label: &str, size: Option<Vec2<f32>>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let size = size.unwrap_or([0.0, 0.0].into());
+    let open = unsafe { ffi::igBeginListBox(label.as_ptr(), size.into()) };
+    Ok(open != 0)
+}
+
+/// Begins a full-screen menu bar at the top of the screen. If the
+/// function returns true, its menus must be added followed by a call
+/// to [`end_main_menu_bar`].
+pub fn begin_main_menu_bar() -> bool {
+    unsafe { ffi::igBeginMainMenuBar() != 0 }
+}
+
+/// Begins a sub-menu. If `enabled` is false, the menu is grayed out
+/// and cannot be opened. If the function returns true, the menu is
+/// open and its items must be added followed by a call to
+/// [`end_menu`].
+pub fn begin_menu(label: &str, enabled: bool) -> Result<bool> {
+    let label = CString::new(label)?;
+    let open = unsafe { ffi::igBeginMenu(label.as_ptr(), enabled as c_uchar) };
+    Ok(open != 0)
+}
+
+/// Begins the menu bar of the current window, which must have been
+/// created with [`WINDOW_FLAGS_MENU_BAR`]. If the function returns
+/// true, its menus must be added followed by a call to
+/// [`end_menu_bar`].
+pub fn begin_menu_bar() -> bool {
+    unsafe { ffi::igBeginMenuBar() != 0 }
+}
+
+/// Begins a popup previously opened with [`open_popup`]. If the
+/// function returns true, the popup is open and its contents must be
+/// added followed by a call to [`end_popup`].
+pub fn begin_popup(str_id: &str, flags: Option<i32>) -> Result<bool> {
+    let str_id = CString::new(str_id)?;
+    let flags = flags.unwrap_or(0);
+    let open = unsafe { ffi::igBeginPopup(str_id.as_ptr(), flags) };
+    Ok(open != 0)
+}
+
+/// Begins a popup that is opened when clicking on the last item. If
+/// `str_id` is [`Option::None`], the ID of the last item is used. If
+/// `flags` is [`Option::None`], the popup is opened with the right
+/// mouse button. If the function returns true, the popup is open and
+/// its contents must be added followed by a call to [`end_popup`].
+pub fn begin_popup_context_item(str_id: Option<&str>, flags: Option<i32>) -> Result<bool> {
+    let str_id = str_id.map(CString::new).transpose()?;
+    let flags = flags.unwrap_or(POPUP_FLAGS_MOUSE_BUTTON_RIGHT);
+    let open = unsafe {
+        ffi::igBeginPopupContextItem(str_id.as_ref().map_or(ptr::null(), |s| s.as_ptr()), flags)
+    };
+    Ok(open != 0)
+}
+
+/// Begins a popup that is opened when clicking on the current
+/// window. See [`begin_popup_context_item`].
+pub fn begin_popup_context_window(str_id: Option<&str>, flags: Option<i32>) -> Result<bool> {
+    let str_id = str_id.map(CString::new).transpose()?;
+    let flags = flags.unwrap_or(POPUP_FLAGS_MOUSE_BUTTON_RIGHT);
+    let open = unsafe {
+        ffi::igBeginPopupContextWindow(str_id.as_ref().map_or(ptr::null(), |s| s.as_ptr()), flags)
+    };
+    Ok(open != 0)
+}
+
+/// Begins a modal popup previously opened with [`open_popup`], which
+/// blocks interactions behind it. If `open` is [`Option::Some`], it
+/// shows a close button and reports whether the popup is open. If the
+/// function returns true, the popup is open and its contents must be
+/// added followed by a call to [`end_popup`].
+pub fn begin_popup_modal(name: &str, open: Option<&mut bool>, flags: Option<i32>) -> Result<bool> {
+    let name = CString::new(name)?;
+    let flags = flags.unwrap_or(0);
+    let visible = match open {
+        Some(open) => {
+            let mut copen: c_uchar = if *open { 1 } else { 0 };
+            let visible = unsafe { ffi::igBeginPopupModal(name.as_ptr(), &mut copen, flags) };
+            *open = copen != 0;
+            visible
+        }
+        None => unsafe { ffi::igBeginPopupModal(name.as_ptr(), ptr::null_mut(), flags) },
+    };
+    Ok(visible != 0)
+}
+
+/// Begins a tab bar. If the function returns true, its tab items must
+/// be added followed by a call to [`end_tab_bar`].
+pub fn begin_tab_bar(str_id: &str, flags: Option<i32>) -> Result<bool> {
+    let str_id = CString::new(str_id)?;
+    let flags = flags.unwrap_or(0);
+    let visible = unsafe { ffi::igBeginTabBar(str_id.as_ptr(), flags) };
+    Ok(visible != 0)
+}
+
+/// Begins a tab item. If `open` is [`Option::Some`], it shows a
+/// close button and reports whether the tab is open. If the function
+/// returns true, the tab is selected and its contents must be added
+/// followed by a call to [`end_tab_item`].
+pub fn begin_tab_item(label: &str, open: Option<&mut bool>, flags: Option<i32>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let flags = flags.unwrap_or(0);
+    let selected = match open {
+        Some(open) => {
+            let mut copen: c_uchar = if *open { 1 } else { 0 };
+            let selected = unsafe { ffi::igBeginTabItem(label.as_ptr(), &mut copen, flags) };
+            *open = copen != 0;
+            selected
+        }
+        None => unsafe { ffi::igBeginTabItem(label.as_ptr(), ptr::null_mut(), flags) },
+    };
+    Ok(selected != 0)
+}
+
+/// Begins a table with the given number of columns. If `outer_size`
+/// is [`Option::None`], the table spans the available width. If the
+/// function returns true, the table contents must be added followed
+/// by a call to [`end_table`].
+pub fn begin_table(
+    str_id: &str,
+    columns: usize,
+    flags: Option<i32>,
+    outer_size: Option<Vec2<f32>>,
+    inner_width: Option<f32>,
+) -> Result<bool> {
+    let str_id = CString::new(str_id)?;
+    let flags = flags.unwrap_or(0);
+    let outer_size = outer_size.unwrap_or([0.0, 0.0].into());
+    let inner_width = inner_width.unwrap_or(0.0);
+    let visible = unsafe {
+        ffi::igBeginTable(
+            str_id.as_ptr(),
+            columns as c_int,
+            flags,
+            outer_size.into(),
+            inner_width,
+        )
+    };
+    Ok(visible != 0)
+}
+
+/// Begins a tooltip window. If the function returns true, its
+/// contents must be added followed by a call to [`end_tooltip`].
+pub fn begin_tooltip() -> bool {
+    unsafe { ffi::igBeginTooltip() != 0 }
+}
+
+/// Adds a text widget preceded by a bullet.
+pub fn bullet_text(s: &str) -> Result<()> {
+    let s = CString::new(s)?;
+    unsafe { ffi::igBulletText(c"%s".as_ptr(), s.as_ptr()) };
+    Ok(())
+}
+
+/// Adds a button widget. If `size` is [`Option::None`], the button
+/// is sized to fit its label. The function returns whether the button
+/// has been pressed.
+pub fn button(label: &str, size: Option<Vec2<f32>>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let size = size.unwrap_or([0.0, 0.0].into());
+    let pressed = unsafe { ffi::igButton(label.as_ptr(), size.into()) };
+    Ok(pressed != 0)
+}
+
+/// Returns the width of the next item given the pushed item widths
+/// and the current cursor position.
+pub fn calc_item_width() -> f32 {
+    unsafe { ffi::igCalcItemWidth() }
+}
+
+/// Adds a checkbox widget. `checked` reports whether the checkbox is
+/// checked. The function returns whether the checkbox has changed.
+pub fn checkbox(label: &str, checked: &mut bool) -> Result<bool> {
     let label = CString::new(label)?;
     let mut cchecked: c_uchar = if *checked { 1 } else { 0 };
     let changed = unsafe { ffi::igCheckbox(label.as_ptr(), &mut cchecked) };
@@ -329,6 +1350,67 @@ pub fn checkbox(label: &str, checked: &mut bool) -> Result<bool> {
     Ok(changed != 0)
 }
 
+/// Closes the popup being built. It must be called between
+/// [`begin_popup`] and [`end_popup`].
+pub fn close_current_popup() {
+    unsafe { ffi::igCloseCurrentPopup() }
+}
+
+/// Adds a collapsing header. The function returns whether the header
+/// is open, in which case its contents must be added. Unlike tree
+/// nodes, collapsing headers do not need to be popped.
+pub fn collapsing_header(label: &str, flags: Option<i32>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let flags = flags.unwrap_or(0);
+    let open = unsafe { ffi::igCollapsingHeader_TreeNodeFlags(label.as_ptr(), flags) };
+    Ok(open != 0)
+}
+
+/// Adds a collapsing header with a close button. `visible` reports
+/// whether the header is visible and is set to false when the close
+/// button is pressed. If `visible` is false, the header is not shown.
+/// The function returns whether the header is open.
+pub fn collapsing_header_visible(
+    label: &str,
+    visible: &mut bool,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let mut cvisible: c_uchar = if *visible { 1 } else { 0 };
+    let flags = flags.unwrap_or(0);
+    let open = unsafe { ffi::igCollapsingHeader_BoolPtr(label.as_ptr(), &mut cvisible, flags) };
+    *visible = cvisible != 0;
+    Ok(open != 0)
+}
+
+/// Adds a color square showing `col`. `desc_id` is used as ID and
+/// shown in the tooltip. If `size` is [`Option::None`], the default
+/// frame height is used. The function returns whether the button has
+/// been pressed.
+pub fn color_button(
+    desc_id: &str,
+    col: Vec4<f32>,
+    flags: Option<i32>,
+    size: Option<Vec2<f32>>,
+) -> Result<bool> {
+    let desc_id = CString::new(desc_id)?;
+    let flags = flags.unwrap_or(0);
+    let size = size.unwrap_or([0.0, 0.0].into());
+    let pressed = unsafe { ffi::igColorButton(desc_id.as_ptr(), col.into(), flags, size.into()) };
+    Ok(pressed != 0)
+}
+
+/// Adds an RGB color edit widget. `col` reports the selected color.
+/// The function returns whether the color has changed.
+pub fn color_edit3(label: &str, col: &mut Vec3<f32>, flags: Option<i32>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let mut ccol: [f32; 3] = (*col).into();
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe { ffi::igColorEdit3(label.as_ptr(), ccol.as_mut_ptr(), flags) };
+    *col = ccol.into();
+    Ok(changed != 0)
+}
+
 /// Ads a color picker widget. `col` reports the selected color. The
 /// function returns whether the color has changed.
 pub fn color_edit4(label: &str, col: &mut Vec4<f32>, flags: Option<i32>) -> Result<bool> {
@@ -340,6 +1422,69 @@ pub fn color_edit4(label: &str, col: &mut Vec4<f32>, flags: Option<i32>) -> Resu
     Ok(changed != 0)
 }
 
+/// Adds an RGB color picker widget. `col` reports the selected
+/// color. The function returns whether the color has changed.
+pub fn color_picker3(label: &str, col: &mut Vec3<f32>, flags: Option<i32>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let mut ccol: [f32; 3] = (*col).into();
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe { ffi::igColorPicker3(label.as_ptr(), ccol.as_mut_ptr(), flags) };
+    *col = ccol.into();
+    Ok(changed != 0)
+}
+
+/// Adds an RGBA color picker widget. If `ref_col` is
+/// [`Option::Some`], it is shown next to the current color for
+/// comparison. `col` reports the selected color. The function returns
+/// whether the color has changed.
+pub fn color_picker4(
+    label: &str,
+    col: &mut Vec4<f32>,
+    flags: Option<i32>,
+    ref_col: Option<Vec4<f32>>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let mut ccol: [f32; 4] = (*col).into();
+    let flags = flags.unwrap_or(0);
+    let ref_col: Option<[f32; 4]> = ref_col.map(Into::into);
+    let changed = unsafe {
+        ffi::igColorPicker4(
+            label.as_ptr(),
+            ccol.as_mut_ptr(),
+            flags,
+            ref_col.as_ref().map_or(ptr::null(), |c| c.as_ptr()),
+        )
+    };
+    *col = ccol.into();
+    Ok(changed != 0)
+}
+
+/// Adds a combo box listing `items`. `current` reports the index of
+/// the selected item. The function returns whether the selection has
+/// changed.
+pub fn combo(label: &str, current: &mut usize, items: &[&str]) -> Result<bool> {
+    let label = CString::new(label)?;
+    let items = items
+        .iter()
+        .map(|item| CString::new(*item))
+        .collect::<result::Result<Vec<_>, _>>()?;
+    let item_ptrs = items.iter().map(|item| item.as_ptr()).collect::<Vec<_>>();
+    let mut current_item = c_int::try_from(*current).unwrap_or(-1);
+    let changed = unsafe {
+        ffi::igCombo_Str_arr(
+            label.as_ptr(),
+            &mut current_item,
+            item_ptrs.as_ptr(),
+            item_ptrs.len() as c_int,
+            -1,
+        )
+    };
+    if changed != 0 {
+        *current = current_item as usize;
+    }
+    Ok(changed != 0)
+}
+
 /// Creates a context.
 pub fn create_context(font_atlas: Option<FontAtlas>) -> Context {
     let font_atlas = font_atlas.map_or(ptr::null_mut(), |fa| fa.as_mut_ptr());
@@ -347,6 +1492,205 @@ pub fn create_context(font_atlas: Option<FontAtlas>) -> Context {
     Context(ctx)
 }
 
+/// Curve editor handle being dragged.
+#[derive(Clone, Copy)]
+enum CurveHandle {
+    Point,
+    TangentIn,
+    TangentOut,
+}
+
+/// Curve editor drag state. It stores the ImGui ID of the widget, the
+/// index of the key point and the handle being dragged.
+static CURVE_EDIT_DRAG: Mutex<Option<(u32, usize, CurveHandle)>> = Mutex::new(None);
+
+/// Packs a color as expected by the draw list functions.
+fn col32(r: u8, g: u8, b: u8, a: u8) -> u32 {
+    (a as u32) << 24 | (b as u32) << 16 | (g as u32) << 8 | r as u32
+}
+
+/// Adds a curve editor widget of the specified `size`. Both axes of
+/// the curve are normalized to the `[0, 1]` range. Key points and
+/// their tangent handles can be dragged with the mouse.
+/// Double-clicking adds a key point or removes the one under the
+/// cursor. The function returns whether the curve has changed.
+pub fn curve_edit(label: &str, curve: &mut Curve, size: Vec2<f32>) -> Result<bool> {
+    const RADIUS: f32 = 4.0;
+    const HANDLE_LEN: f32 = 0.1;
+    const SEGMENTS: usize = 64;
+
+    let label = CString::new(label)?;
+
+    let mut origin = ffi::ImVec2::from(Vec2::from([0.0, 0.0]));
+    unsafe { ffi::igGetCursorScreenPos(&mut origin) };
+    let origin: Vec2<f32> = origin.into();
+    let to_screen = |x: f32, y: f32| -> ffi::ImVec2 {
+        Vec2::from([origin[0] + x * size[0], origin[1] + (1.0 - y) * size[1]]).into()
+    };
+    let from_screen = |p: Vec2<f32>| -> (f32, f32) {
+        (
+            (p[0] - origin[0]) / size[0],
+            1.0 - (p[1] - origin[1]) / size[1],
+        )
+    };
+
+    unsafe { ffi::igInvisibleButton(label.as_ptr(), size.into(), 0) };
+    let id = unsafe { ffi::igGetItemID() };
+    let active = unsafe { ffi::igIsItemActive() != 0 };
+    let hovered = unsafe { ffi::igIsItemHovered(0) != 0 };
+
+    let io = unsafe { &*ffi::igGetIO() };
+    let mouse: Vec2<f32> = io.MousePos.into();
+    let (mx, my) = from_screen(mouse);
+
+    let handles = |p: &CurvePoint| {
+        [
+            (CurveHandle::Point, p.x, p.y),
+            (
+                CurveHandle::TangentIn,
+                p.x - HANDLE_LEN,
+                p.y - HANDLE_LEN * p.tangent,
+            ),
+            (
+                CurveHandle::TangentOut,
+                p.x + HANDLE_LEN,
+                p.y + HANDLE_LEN * p.tangent,
+            ),
+        ]
+    };
+    let hit = curve.points().iter().enumerate().find_map(|(i, p)| {
+        handles(p).into_iter().find_map(|(handle, x, y)| {
+            let hp: Vec2<f32> = to_screen(x, y).into();
+            let (dx, dy) = (hp[0] - mouse[0], hp[1] - mouse[1]);
+            (dx * dx + dy * dy <= (2.0 * RADIUS).powi(2)).then_some((i, handle))
+        })
+    });
+
+    let mut changed = false;
+    let mut drag = CURVE_EDIT_DRAG.lock().unwrap();
+    if hovered && io.MouseDoubleClicked[0] != 0 {
+        match hit {
+            Some((i, CurveHandle::Point)) if curve.points().len() > 1 => {
+                curve.remove(i);
+            }
+            Some(_) => {}
+            None => {
+                curve.insert(CurvePoint::new(mx.clamp(0.0, 1.0), my.clamp(0.0, 1.0), 0.0));
+            }
+        }
+        *drag = None;
+        changed = true;
+    } else if hovered && io.MouseClicked[0] != 0 {
+        *drag = hit.map(|(i, handle)| (id, i, handle));
+    }
+
+    match drag.as_ref() {
+        Some((drag_id, i, handle)) if active && *drag_id == id => {
+            let (i, handle) = (*i, *handle);
+            let min_x = if i > 0 { curve.points()[i - 1].x } else { 0.0 };
+            let max_x = curve.points().get(i + 1).map_or(1.0, |p| p.x);
+            if let Some(p) = curve.point_mut(i) {
+                match handle {
+                    CurveHandle::Point => {
+                        p.x = mx.clamp(min_x, max_x);
+                        p.y = my.clamp(0.0, 1.0);
+                    }
+                    CurveHandle::TangentIn if mx < p.x => p.tangent = (p.y - my) / (p.x - mx),
+                    CurveHandle::TangentOut if mx > p.x => p.tangent = (my - p.y) / (mx - p.x),
+                    _ => {}
+                }
+                changed = true;
+            }
+        }
+        Some((drag_id, _, _)) if *drag_id == id => *drag = None,
+        _ => {}
+    }
+
+    let draw_list = unsafe { ffi::igGetWindowDrawList() };
+    let bg_col = col32(30, 30, 30, 255);
+    let grid_col = col32(70, 70, 70, 255);
+    let curve_col = col32(255, 200, 60, 255);
+    let handle_col = col32(150, 150, 150, 255);
+    let point_col = col32(255, 255, 255, 255);
+
+    unsafe {
+        ffi::ImDrawList_AddRectFilled(
+            draw_list,
+            to_screen(0.0, 1.0),
+            to_screen(1.0, 0.0),
+            bg_col,
+            0.0,
+            0,
+        );
+        for i in 1..4 {
+            let t = i as f32 / 4.0;
+            ffi::ImDrawList_AddLine(
+                draw_list,
+                to_screen(t, 0.0),
+                to_screen(t, 1.0),
+                grid_col,
+                1.0,
+            );
+            ffi::ImDrawList_AddLine(
+                draw_list,
+                to_screen(0.0, t),
+                to_screen(1.0, t),
+                grid_col,
+                1.0,
+            );
+        }
+        ffi::ImDrawList_AddRect(
+            draw_list,
+            to_screen(0.0, 1.0),
+            to_screen(1.0, 0.0),
+            grid_col,
+            0.0,
+            0,
+            1.0,
+        );
+
+        for i in 0..SEGMENTS {
+            let x0 = i as f32 / SEGMENTS as f32;
+            let x1 = (i + 1) as f32 / SEGMENTS as f32;
+            ffi::ImDrawList_AddLine(
+                draw_list,
+                to_screen(x0, curve.sample(x0)),
+                to_screen(x1, curve.sample(x1)),
+                curve_col,
+                2.0,
+            );
+        }
+
+        for p in curve.points() {
+            let [(_, px, py), (_, ix, iy), (_, ox, oy)] = handles(p);
+            ffi::ImDrawList_AddLine(
+                draw_list,
+                to_screen(ix, iy),
+                to_screen(ox, oy),
+                handle_col,
+                1.0,
+            );
+            ffi::ImDrawList_AddCircleFilled(
+                draw_list,
+                to_screen(ix, iy),
+                RADIUS * 0.75,
+                handle_col,
+                0,
+            );
+            ffi::ImDrawList_AddCircleFilled(
+                draw_list,
+                to_screen(ox, oy),
+                RADIUS * 0.75,
+                handle_col,
+                0,
+            );
+            ffi::ImDrawList_AddCircleFilled(draw_list, to_screen(px, py), RADIUS, point_col, 0);
+        }
+    }
+
+    Ok(changed)
+}
+
 /// Destroys the specified context. If [`Option::None`], destroy
 /// current context.
 pub fn destroy_context(ctx: Option<Context>) {
@@ -354,20 +1698,736 @@ pub fn destroy_context(ctx: Option<Context>) {
     unsafe { ffi::igDestroyContext(ctx) };
 }
 
-/// Pop window from the stack.
-pub fn end() {
-    unsafe { ffi::igEnd() }
+/// Adds a drag float widget. `speed` is the value change per pixel
+/// of mouse movement and defaults to 1. If `range` is
+/// [`Option::Some`], the value is clamped to it. `v` reports the
+/// selected value. The function returns whether the value has
+/// changed.
+pub fn drag_float(
+    label: &str,
+    v: &mut f32,
+    speed: Option<f32>,
+    range: Option<(f32, f32)>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let (min, max) = range.unwrap_or((0.0, 0.0));
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igDragFloat(
+            label.as_ptr(),
+            v,
+            speed.unwrap_or(1.0),
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a drag widget for two floats. See [`drag_float`].
+pub fn drag_float2(
+    label: &str,
+    v: &mut Vec2<f32>,
+    speed: Option<f32>,
+    range: Option<(f32, f32)>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let (min, max) = range.unwrap_or((0.0, 0.0));
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igDragFloat2(
+            label.as_ptr(),
+            v.as_mut_ptr(),
+            speed.unwrap_or(1.0),
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a drag widget for three floats. See [`drag_float`].
+pub fn drag_float3(
+    label: &str,
+    v: &mut Vec3<f32>,
+    speed: Option<f32>,
+    range: Option<(f32, f32)>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let (min, max) = range.unwrap_or((0.0, 0.0));
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igDragFloat3(
+            label.as_ptr(),
+            v.as_mut_ptr(),
+            speed.unwrap_or(1.0),
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a drag widget for four floats. See [`drag_float`].
+pub fn drag_float4(
+    label: &str,
+    v: &mut Vec4<f32>,
+    speed: Option<f32>,
+    range: Option<(f32, f32)>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let (min, max) = range.unwrap_or((0.0, 0.0));
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igDragFloat4(
+            label.as_ptr(),
+            v.as_mut_ptr(),
+            speed.unwrap_or(1.0),
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a drag widget for a range, with one handle for each bound.
+/// `v` reports the selected `(min, max)` bounds. See [`drag_float`].
+pub fn drag_float_range2(
+    label: &str,
+    v: &mut (f32, f32),
+    speed: Option<f32>,
+    range: Option<(f32, f32)>,
+    format: Option<&str>,
+    format_max: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let (min, max) = range.unwrap_or((0.0, 0.0));
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let format_max = format_max.map(CString::new).transpose()?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igDragFloatRange2(
+            label.as_ptr(),
+            &mut v.0,
+            &mut v.1,
+            speed.unwrap_or(1.0),
+            min,
+            max,
+            format.as_ptr(),
+            format_max.as_ref().map_or(ptr::null(), |f| f.as_ptr()),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a drag integer widget. See [`drag_float`].
+pub fn drag_int(
+    label: &str,
+    v: &mut i32,
+    speed: Option<f32>,
+    range: Option<(i32, i32)>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let (min, max) = range.unwrap_or((0, 0));
+    let format = format.map_or(CString::new("%d"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igDragInt(
+            label.as_ptr(),
+            v,
+            speed.unwrap_or(1.0),
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds an invisible item of the given size, which can be used to
+/// reserve space.
+pub fn dummy(size: Vec2<f32>) {
+    unsafe { ffi::igDummy(size.into()) }
+}
+
+/// Pop window from the stack.
+pub fn end() {
+    unsafe { ffi::igEnd() }
+}
+
+/// Ends a child window.
+pub fn end_child() {
+    unsafe { ffi::igEndChild() }
+}
+
+/// Ends a combo box. It must only be called if [`begin_combo`]
+/// returned true.
+pub fn end_combo() {
+    unsafe { ffi::igEndCombo() }
+}
+
+/// Ends a group.
+pub fn end_group() {
+    unsafe { ffi::igEndGroup() }
+}
+
+/// Ends a list box. It must only be called if [`begin_list_box`]
+/// returned true.
+pub fn end_list_box() {
+    unsafe { ffi::igEndListBox() }
+}
+
+/// Ends the main menu bar. It must only be called if
+/// [`begin_main_menu_bar`] returned true.
+pub fn end_main_menu_bar() {
+    unsafe { ffi::igEndMainMenuBar() }
+}
+
+/// Ends a sub-menu. It must only be called if [`begin_menu`] returned
+/// true.
+pub fn end_menu() {
+    unsafe { ffi::igEndMenu() }
+}
+
+/// Ends the menu bar of the current window. It must only be called
+/// if [`begin_menu_bar`] returned true.
+pub fn end_menu_bar() {
+    unsafe { ffi::igEndMenuBar() }
+}
+
+/// Ends a popup. It must only be called if one of the `begin_popup*`
+/// functions returned true.
+pub fn end_popup() {
+    unsafe { ffi::igEndPopup() }
+}
+
+/// Ends a tab bar. It must only be called if [`begin_tab_bar`]
+/// returned true.
+pub fn end_tab_bar() {
+    unsafe { ffi::igEndTabBar() }
+}
+
+/// Ends a tab item. It must only be called if [`begin_tab_item`]
+/// returned true.
+pub fn end_tab_item() {
+    unsafe { ffi::igEndTabItem() }
+}
+
+/// Ends a table. It must only be called if [`begin_table`] returned
+/// true.
+pub fn end_table() {
+    unsafe { ffi::igEndTable() }
+}
+
+/// Ends a tooltip window. It must only be called if
+/// [`begin_tooltip`] returned true.
+pub fn end_tooltip() {
+    unsafe { ffi::igEndTooltip() }
+}
+
+/// Returns the draw data required to render a frame.
+pub fn get_draw_data() -> DrawData {
+    let draw_data = unsafe { ffi::igGetDrawData() };
+    DrawData(draw_data)
+}
+
+/// Adds an image widget showing `texture`. `uv0` and `uv1` default to
+/// `(0, 0)` and `(1, 1)`. Note that OpenGL textures have their origin
+/// at the bottom-left corner, so render targets must be displayed
+/// with `uv0` set to `(0, 1)` and `uv1` set to `(1, 0)`. `tint`
+/// defaults to white and `border` to transparent.
+pub fn image(
+    texture: gl::Texture,
+    size: Vec2<f32>,
+    uv0: Option<Vec2<f32>>,
+    uv1: Option<Vec2<f32>>,
+    tint: Option<Vec4<f32>>,
+    border: Option<Vec4<f32>>,
+) {
+    let uv0 = uv0.unwrap_or([0.0, 0.0].into());
+    let uv1 = uv1.unwrap_or([1.0, 1.0].into());
+    let tint = tint.unwrap_or([1.0, 1.0, 1.0, 1.0].into());
+    let border = border.unwrap_or([0.0, 0.0, 0.0, 0.0].into());
+    unsafe {
+        ffi::igImage(
+            texture.id().into(),
+            size.into(),
+            uv0.into(),
+            uv1.into(),
+            tint.into(),
+            border.into(),
+        )
+    }
+}
+
+/// Adds a button showing `texture`. `bg` is the background color and
+/// defaults to transparent. See [`image`] for the rest of arguments.
+/// The function returns whether the button has been pressed.
+pub fn image_button(
+    str_id: &str,
+    texture: gl::Texture,
+    size: Vec2<f32>,
+    uv0: Option<Vec2<f32>>,
+    uv1: Option<Vec2<f32>>,
+    bg: Option<Vec4<f32>>,
+    tint: Option<Vec4<f32>>,
+) -> Result<bool> {
+    let str_id = CString::new(str_id)?;
+    let uv0 = uv0.unwrap_or([0.0, 0.0].into());
+    let uv1 = uv1.unwrap_or([1.0, 1.0].into());
+    let bg = bg.unwrap_or([0.0, 0.0, 0.0, 0.0].into());
+    let tint = tint.unwrap_or([1.0, 1.0, 1.0, 1.0].into());
+    let pressed = unsafe {
+        ffi::igImageButton(
+            str_id.as_ptr(),
+            texture.id().into(),
+            size.into(),
+            uv0.into(),
+            uv1.into(),
+            bg.into(),
+            tint.into(),
+        )
+    };
+    Ok(pressed != 0)
+}
+
+/// Moves the content position to the right. If `indent_w` is
+/// [`Option::None`], the default indent spacing of the style is used.
+pub fn indent(indent_w: Option<f32>) {
+    let indent_w = indent_w.unwrap_or(0.0);
+    unsafe { ffi::igIndent(indent_w) }
+}
+
+/// Adds an input widget for a double. If `step` is non-zero, it
+/// shows +/- buttons. `v` reports the entered value. The function
+/// returns whether the value has changed.
+pub fn input_double(
+    label: &str,
+    v: &mut f64,
+    step: Option<f64>,
+    step_fast: Option<f64>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map_or(CString::new("%.6f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igInputDouble(
+            label.as_ptr(),
+            v,
+            step.unwrap_or(0.0),
+            step_fast.unwrap_or(0.0),
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds an input widget for a float. If `step` is non-zero, it shows
+/// +/- buttons. `v` reports the entered value. The function returns
+/// whether the value has changed.
+pub fn input_float(
+    label: &str,
+    v: &mut f32,
+    step: Option<f32>,
+    step_fast: Option<f32>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igInputFloat(
+            label.as_ptr(),
+            v,
+            step.unwrap_or(0.0),
+            step_fast.unwrap_or(0.0),
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds an input widget for three floats. `v` reports the entered
+/// values. The function returns whether any value has changed.
+pub fn input_float3(
+    label: &str,
+    v: &mut Vec3<f32>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+    let changed =
+        unsafe { ffi::igInputFloat3(label.as_ptr(), v.as_mut_ptr(), format.as_ptr(), flags) };
+    Ok(changed != 0)
+}
+
+/// Adds an input widget for an integer with +/- buttons. `step`
+/// and `step_fast` default to 1 and 100. `v` reports the entered
+/// value. The function returns whether the value has changed.
+pub fn input_int(
+    label: &str,
+    v: &mut i32,
+    step: Option<i32>,
+    step_fast: Option<i32>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let flags = flags.unwrap_or(0);
+    let changed = unsafe {
+        ffi::igInputInt(
+            label.as_ptr(),
+            v,
+            step.unwrap_or(1),
+            step_fast.unwrap_or(100),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds an input widget for every element of `values`. If `step` is
+/// [`Option::Some`], it shows +/- buttons. If `format` is
+/// [`Option::None`], the default format of the scalar type is used.
+/// The function returns whether any value has changed.
+pub fn input_scalar_n<T: Scalar>(
+    label: &str,
+    values: &mut [T],
+    step: Option<T>,
+    step_fast: Option<T>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map(CString::new).transpose()?;
+    let flags = flags.unwrap_or(0);
+    let step = step
+        .as_ref()
+        .map_or(ptr::null(), |s| s as *const T as *const c_void);
+    let step_fast = step_fast
+        .as_ref()
+        .map_or(ptr::null(), |s| s as *const T as *const c_void);
+    let changed = unsafe {
+        ffi::igInputScalarN(
+            label.as_ptr(),
+            T::DATA_TYPE,
+            values.as_mut_ptr() as *mut c_void,
+            values.len() as c_int,
+            step,
+            step_fast,
+            format.as_ref().map_or(ptr::null(), |f| f.as_ptr()),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a single-line text input widget bound to `text`. The
+/// internal buffer grows as needed, so there is no length limit. The
+/// function returns whether the text has changed or, if
+/// [`INPUT_TEXT_FLAGS_ENTER_RETURNS_TRUE`] is set, whether Enter has
+/// been pressed.
+pub fn input_text(label: &str, text: &mut String, flags: Option<i32>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let flags = flags.unwrap_or(0) | INPUT_TEXT_FLAGS_CALLBACK_RESIZE;
+    let mut state = InputTextState::new(text, None)?;
+    let changed = unsafe {
+        ffi::igInputText(
+            label.as_ptr(),
+            state.buf.as_mut_ptr() as *mut c_char,
+            state.buf.len(),
+            flags,
+            Some(input_text_callback),
+            &mut state as *mut InputTextState as *mut c_void,
+        )
+    };
+    store_input_text(&state.buf, text);
+    Ok(changed != 0)
+}
+
+/// Adds a multi-line text input widget bound to `text`. If `size` is
+/// [`Option::None`], the default size is used. If `callback` is
+/// [`Option::Some`], it is called for the events enabled by the
+/// `INPUT_TEXT_FLAGS_CALLBACK_*` flags. Its return value is only
+/// used with [`INPUT_TEXT_FLAGS_CALLBACK_CHAR_FILTER`], where
+/// returning a non-zero value discards the character. The function
+/// returns whether the text has changed.
+pub fn input_text_multiline(
+    label: &str,
+    text: &mut String,
+    size: Option<Vec2<f32>>,
+    flags: Option<i32>,
+    callback: Option<&mut dyn FnMut(&mut InputTextCallbackData) -> i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let size = size.unwrap_or([0.0, 0.0].into());
+    let flags = flags.unwrap_or(0) | INPUT_TEXT_FLAGS_CALLBACK_RESIZE;
+    let mut state = InputTextState::new(text, callback)?;
+    let changed = unsafe {
+        ffi::igInputTextMultiline(
+            label.as_ptr(),
+            state.buf.as_mut_ptr() as *mut c_char,
+            state.buf.len(),
+            size.into(),
+            flags,
+            Some(input_text_callback),
+            &mut state as *mut InputTextState as *mut c_void,
+        )
+    };
+    store_input_text(&state.buf, text);
+    Ok(changed != 0)
+}
+
+/// Text input state passed as user data to [`input_text_callback`].
+struct InputTextState<'a> {
+    buf: Vec<u8>,
+    callback: Option<&'a mut dyn FnMut(&mut InputTextCallbackData) -> i32>,
+}
+
+impl<'a> InputTextState<'a> {
+    /// Creates the state of a text input bound to `text`.
+    fn new(
+        text: &str,
+        callback: Option<&'a mut dyn FnMut(&mut InputTextCallbackData) -> i32>,
+    ) -> Result<InputTextState<'a>> {
+        let buf = CString::new(text)?.into_bytes_with_nul();
+        Ok(InputTextState { buf, callback })
+    }
+}
+
+/// Text input callback. It grows the buffer when Dear ImGui requests
+/// it and forwards any other event to the user callback.
+unsafe extern "C" fn input_text_callback(data: *mut ffi::ImGuiInputTextCallbackData) -> c_int {
+    let state = &mut *((*data).UserData as *mut InputTextState);
+    if (*data).EventFlag == INPUT_TEXT_FLAGS_CALLBACK_RESIZE {
+        state.buf.resize((*data).BufSize as usize, 0);
+        (*data).Buf = state.buf.as_mut_ptr() as *mut c_char;
+        return 0;
+    }
+    match &mut state.callback {
+        Some(callback) => callback(&mut InputTextCallbackData(data)),
+        None => 0,
+    }
+}
+
+/// Stores the NUL-terminated contents of a text input buffer into
+/// `text`.
+fn store_input_text(buf: &[u8], text: &mut String) {
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    let contents = String::from_utf8_lossy(&buf[..len]);
+    if *text != contents {
+        *text = contents.into_owned();
+    }
+}
+
+/// Adds an invisible button widget of the specified `size`. It is
+/// useful to build custom widgets that react to clicks. The function
+/// returns whether the button has been pressed.
+pub fn invisible_button(str_id: &str, size: Vec2<f32>, flags: Option<i32>) -> Result<bool> {
+    let str_id = CString::new(str_id)?;
+    let flags = flags.unwrap_or(0);
+    let pressed = unsafe { ffi::igInvisibleButton(str_id.as_ptr(), size.into(), flags) };
+    Ok(pressed != 0)
+}
+
+/// Adds a text widget displaying a value and a label, aligned the
+/// same way as value widgets.
+pub fn label_text(label: &str, s: &str) -> Result<()> {
+    let label = CString::new(label)?;
+    let s = CString::new(s)?;
+    unsafe { ffi::igLabelText(label.as_ptr(), c"%s".as_ptr(), s.as_ptr()) };
+    Ok(())
+}
+
+/// Loads settings from a string in .ini format. When used to restore
+/// a layout at runtime, it must be called before [`new_frame`].
+pub fn load_ini_settings_from_memory(ini_data: &str) {
+    unsafe { ffi::igLoadIniSettingsFromMemory(ini_data.as_ptr() as *const c_char, ini_data.len()) }
+}
+
+/// Adds a menu item. `shortcut` is only displayed, it is not
+/// processed. If `checked` is [`Option::Some`], a check mark is shown
+/// and toggled when the item is activated. The function returns
+/// whether the item has been activated.
+pub fn menu_item(
+    label: &str,
+    shortcut: Option<&str>,
+    checked: Option<&mut bool>,
+    enabled: bool,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let shortcut = shortcut.map(CString::new).transpose()?;
+    let shortcut = shortcut.as_ref().map_or(ptr::null(), |s| s.as_ptr());
+    let activated = match checked {
+        Some(checked) => {
+            let mut cchecked: c_uchar = if *checked { 1 } else { 0 };
+            let activated = unsafe {
+                ffi::igMenuItem_BoolPtr(label.as_ptr(), shortcut, &mut cchecked, enabled as c_uchar)
+            };
+            *checked = cchecked != 0;
+            activated
+        }
+        None => unsafe { ffi::igMenuItem_Bool(label.as_ptr(), shortcut, 0, enabled as c_uchar) },
+    };
+    Ok(activated != 0)
+}
+
+/// Starts a new frame.
+pub fn new_frame() {
+    unsafe { ffi::igNewFrame() }
+}
+
+/// Undoes a [`same_line`] or forces a new line when in a horizontal
+/// layout.
+pub fn new_line() {
+    unsafe { ffi::igNewLine() }
+}
+
+/// Marks the popup as open. It must not be called every frame.
+pub fn open_popup(str_id: &str, flags: Option<i32>) -> Result<()> {
+    let str_id = CString::new(str_id)?;
+    let flags = flags.unwrap_or(0);
+    unsafe { ffi::igOpenPopup_Str(str_id.as_ptr(), flags) };
+    Ok(())
+}
+
+/// Adds a histogram plot of `values`. See [`plot_lines`].
+pub fn plot_histogram(
+    label: &str,
+    values: &[f32],
+    values_offset: Option<usize>,
+    overlay: Option<&str>,
+    scale: Option<(f32, f32)>,
+    size: Option<Vec2<f32>>,
+) -> Result<()> {
+    let label = CString::new(label)?;
+    let overlay = overlay.map(CString::new).transpose()?;
+    let (scale_min, scale_max) = scale.unwrap_or((f32::MAX, f32::MAX));
+    let size = size.unwrap_or([0.0, 0.0].into());
+    unsafe {
+        ffi::igPlotHistogram_FloatPtr(
+            label.as_ptr(),
+            values.as_ptr(),
+            values.len() as c_int,
+            values_offset.unwrap_or(0) as c_int,
+            overlay.as_ref().map_or(ptr::null(), |o| o.as_ptr()),
+            scale_min,
+            scale_max,
+            size.into(),
+            mem::size_of::<f32>() as c_int,
+        )
+    };
+    Ok(())
+}
+
+/// Adds a line plot of `values`. `values_offset` is the index of the
+/// first value to plot, which is convenient for ring buffers. If
+/// `scale` is [`Option::None`], the scale is computed from the
+/// values. `overlay` is displayed on top of the plot.
+pub fn plot_lines(
+    label: &str,
+    values: &[f32],
+    values_offset: Option<usize>,
+    overlay: Option<&str>,
+    scale: Option<(f32, f32)>,
+    size: Option<Vec2<f32>>,
+) -> Result<()> {
+    let label = CString::new(label)?;
+    let overlay = overlay.map(CString::new).transpose()?;
+    let (scale_min, scale_max) = scale.unwrap_or((f32::MAX, f32::MAX));
+    let size = size.unwrap_or([0.0, 0.0].into());
+    unsafe {
+        ffi::igPlotLines_FloatPtr(
+            label.as_ptr(),
+            values.as_ptr(),
+            values.len() as c_int,
+            values_offset.unwrap_or(0) as c_int,
+            overlay.as_ref().map_or(ptr::null(), |o| o.as_ptr()),
+            scale_min,
+            scale_max,
+            size.into(),
+            mem::size_of::<f32>() as c_int,
+        )
+    };
+    Ok(())
+}
+
+/// Pops the item width pushed by [`push_item_width`].
+pub fn pop_item_width() {
+    unsafe { ffi::igPopItemWidth() }
+}
+
+/// Adds a progress bar filled up to `fraction`, in the range
+/// `[0.0, 1.0]`. If `size` is [`Option::None`], the bar spans the
+/// available width. If `overlay` is [`Option::None`], the percentage
+/// is displayed.
+pub fn progress_bar(fraction: f32, size: Option<Vec2<f32>>, overlay: Option<&str>) -> Result<()> {
+    let size = size.unwrap_or([-f32::MIN_POSITIVE, 0.0].into());
+    let overlay = overlay.map(CString::new).transpose()?;
+    unsafe {
+        ffi::igProgressBar(
+            fraction,
+            size.into(),
+            overlay.as_ref().map_or(ptr::null(), |o| o.as_ptr()),
+        )
+    };
+    Ok(())
+}
+
+/// Pushes the width of the upcoming items. A positive value is a
+/// width in pixels and a negative value aligns the items to the right
+/// edge of the window. It must be followed by a call to
+/// [`pop_item_width`].
+pub fn push_item_width(item_width: f32) {
+    unsafe { ffi::igPushItemWidth(item_width) }
 }
 
-/// Returns the draw data required to render a frame.
-pub fn get_draw_data() -> DrawData {
-    let draw_data = unsafe { ffi::igGetDrawData() };
-    DrawData(draw_data)
+/// Adds a radio button that selects `value`. When pressed, `v` is set
+/// to `value`. The button is shown as active if `v` equals `value`.
+/// The function returns whether the button has been pressed.
+pub fn radio_button(label: &str, v: &mut i32, value: i32) -> Result<bool> {
+    let label = CString::new(label)?;
+    let pressed = unsafe { ffi::igRadioButton_IntPtr(label.as_ptr(), v, value) };
+    Ok(pressed != 0)
 }
 
-/// Starts a new frame.
-pub fn new_frame() {
-    unsafe { ffi::igNewFrame() }
+/// Adds a radio button. `active` sets whether the button is shown as
+/// active. The function returns whether the button has been pressed.
+pub fn radio_button_bool(label: &str, active: bool) -> Result<bool> {
+    let label = CString::new(label)?;
+    let pressed = unsafe { ffi::igRadioButton_Bool(label.as_ptr(), active as c_uchar) };
+    Ok(pressed != 0)
 }
 
 /// Renders a frame.
@@ -383,6 +2443,69 @@ pub fn same_line(offset_from_start_x: Option<f32>, spacing: Option<f32>) {
     unsafe { ffi::igSameLine(offset_from_start_x, spacing) }
 }
 
+/// Returns the current settings, including the docking layout, in
+/// .ini format.
+pub fn save_ini_settings_to_memory() -> String {
+    let mut size = 0;
+    let ini_data = unsafe { ffi::igSaveIniSettingsToMemory(&mut size) };
+    let ini_data = unsafe { slice::from_raw_parts(ini_data as *const u8, size) };
+    String::from_utf8_lossy(ini_data).into_owned()
+}
+
+/// Adds a selectable item. `selected` sets whether the item is
+/// highlighted. If `size` is [`Option::None`], the item spans the
+/// available width and uses the text height. The function returns
+/// whether the item has been clicked.
+pub fn selectable(
+    label: &str,
+    selected: bool,
+    flags: Option<i32>,
+    size: Option<Vec2<f32>>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let flags = flags.unwrap_or(0);
+    let size = size.unwrap_or([0.0, 0.0].into());
+    let clicked =
+        unsafe { ffi::igSelectable_Bool(label.as_ptr(), selected as c_uchar, flags, size.into()) };
+    Ok(clicked != 0)
+}
+
+/// Adds a separator, generally horizontal.
+pub fn separator() {
+    unsafe { ffi::igSeparator() }
+}
+
+/// Adds a horizontal separator with a text label.
+pub fn separator_text(label: &str) -> Result<()> {
+    let label = CString::new(label)?;
+    unsafe { ffi::igSeparatorText(label.as_ptr()) };
+    Ok(())
+}
+
+/// Makes the last item the default focused item of a newly appearing
+/// window, such as the selected item of a combo popup.
+pub fn set_item_default_focus() {
+    unsafe { ffi::igSetItemDefaultFocus() }
+}
+
+/// Sets a text tooltip that is shown when the last item is hovered.
+pub fn set_item_tooltip(s: &str) -> Result<()> {
+    let s = CString::new(s)?;
+    unsafe { ffi::igSetItemTooltip(c"%s".as_ptr(), s.as_ptr()) };
+    Ok(())
+}
+
+/// Sets the open state of the next tree node or collapsing header.
+pub fn set_next_item_open(is_open: bool, cond: Option<i32>) {
+    let cond = cond.unwrap_or(0);
+    unsafe { ffi::igSetNextItemOpen(is_open as c_uchar, cond) }
+}
+
+/// Sets the width of the next item. See [`push_item_width`].
+pub fn set_next_item_width(item_width: f32) {
+    unsafe { ffi::igSetNextItemWidth(item_width) }
+}
+
 /// Sets next window position.
 pub fn set_next_window_pos(pos: Vec2<f32>, cond: Option<i32>, pivot: Option<Vec2<f32>>) {
     let cond = cond.unwrap_or(0);
@@ -396,6 +2519,14 @@ pub fn set_next_window_size(size: Vec2<f32>, cond: Option<i32>) {
     unsafe { ffi::igSetNextWindowSize(size.into(), cond) }
 }
 
+/// Sets a text tooltip, replacing any previous one. It is usually
+/// called when an item is hovered.
+pub fn set_tooltip(s: &str) -> Result<()> {
+    let s = CString::new(s)?;
+    unsafe { ffi::igSetTooltip(c"%s".as_ptr(), s.as_ptr()) };
+    Ok(())
+}
+
 /// Shows the Deam ImGui demo window. If `open` is [`Option::Some`],
 /// it shows a window-closing widget in the upper-right corner of the
 /// window, which clicking will set the boolean to false when
@@ -411,6 +2542,27 @@ pub fn show_demo_window(open: Option<&mut bool>) {
     }
 }
 
+/// Adds a slider widget for an angle. `v_rad` is stored in radians
+/// but displayed in degrees. The range is given in degrees and
+/// defaults to `(-360.0, 360.0)`. The function returns whether the
+/// slider value has changed.
+pub fn slider_angle(
+    label: &str,
+    v_rad: &mut f32,
+    range: Option<(f32, f32)>,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let (min, max) = range.unwrap_or((-360.0, 360.0));
+    let format = format.map_or(CString::new("%.0f deg"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+
+    let changed =
+        unsafe { ffi::igSliderAngle(label.as_ptr(), v_rad, min, max, format.as_ptr(), flags) };
+    Ok(changed != 0)
+}
+
 /// Adds a slider float widget. `v` reports the selected value. The
 /// function returns whether the slider value has changed.
 pub fn slider_float(
@@ -430,13 +2582,332 @@ pub fn slider_float(
     Ok(changed != 0)
 }
 
+/// Adds a slider widget for two floats. See [`slider_float`].
+pub fn slider_float2(
+    label: &str,
+    v: &mut Vec2<f32>,
+    min: f32,
+    max: f32,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+
+    let changed = unsafe {
+        ffi::igSliderFloat2(
+            label.as_ptr(),
+            v.as_mut_ptr(),
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a slider widget for three floats. See [`slider_float`].
+pub fn slider_float3(
+    label: &str,
+    v: &mut Vec3<f32>,
+    min: f32,
+    max: f32,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+
+    let changed = unsafe {
+        ffi::igSliderFloat3(
+            label.as_ptr(),
+            v.as_mut_ptr(),
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a slider widget for four floats. See [`slider_float`].
+pub fn slider_float4(
+    label: &str,
+    v: &mut Vec4<f32>,
+    min: f32,
+    max: f32,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+
+    let changed = unsafe {
+        ffi::igSliderFloat4(
+            label.as_ptr(),
+            v.as_mut_ptr(),
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
+/// Adds a slider integer widget. `v` reports the selected value. The
+/// function returns whether the slider value has changed.
+pub fn slider_int(
+    label: &str,
+    v: &mut i32,
+    min: i32,
+    max: i32,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map_or(CString::new("%d"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+
+    let changed = unsafe { ffi::igSliderInt(label.as_ptr(), v, min, max, format.as_ptr(), flags) };
+    Ok(changed != 0)
+}
+
+/// Adds a button widget without frame padding, which is convenient to
+/// embed within text. The function returns whether the button has
+/// been pressed.
+pub fn small_button(label: &str) -> Result<bool> {
+    let label = CString::new(label)?;
+    let pressed = unsafe { ffi::igSmallButton(label.as_ptr()) };
+    Ok(pressed != 0)
+}
+
+/// Adds vertical spacing.
+pub fn spacing() {
+    unsafe { ffi::igSpacing() }
+}
+
+/// Adds a tab that behaves like a button. The function returns
+/// whether the tab has been clicked.
+pub fn tab_item_button(label: &str, flags: Option<i32>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let flags = flags.unwrap_or(0);
+    let clicked = unsafe { ffi::igTabItemButton(label.as_ptr(), flags) };
+    Ok(clicked != 0)
+}
+
+/// Adds a header row using the labels passed to
+/// [`table_setup_column`].
+pub fn table_headers_row() {
+    unsafe { ffi::igTableHeadersRow() }
+}
+
+/// Moves to the next column, or to the first column of the next row
+/// if the current column is the last one. The function returns
+/// whether the column is visible.
+pub fn table_next_column() -> bool {
+    unsafe { ffi::igTableNextColumn() != 0 }
+}
+
+/// Starts a new row with the given `TABLE_ROW_FLAGS_*` flags.
+pub fn table_next_row(flags: Option<i32>, min_row_height: Option<f32>) {
+    let flags = flags.unwrap_or(0);
+    let min_row_height = min_row_height.unwrap_or(0.0);
+    unsafe { ffi::igTableNextRow(flags, min_row_height) }
+}
+
+/// Moves to the specified column. The function returns whether the
+/// column is visible.
+pub fn table_set_column_index(column: usize) -> bool {
+    unsafe { ffi::igTableSetColumnIndex(column as c_int) != 0 }
+}
+
+/// Sets up a column. `init_width_or_weight` is a width for fixed
+/// columns and a weight for stretched columns. `user_id` is reported
+/// back in [`TableColumnSortSpecs`].
+pub fn table_setup_column(
+    label: &str,
+    flags: Option<i32>,
+    init_width_or_weight: Option<f32>,
+    user_id: Option<u32>,
+) -> Result<()> {
+    let label = CString::new(label)?;
+    let flags = flags.unwrap_or(0);
+    let init_width_or_weight = init_width_or_weight.unwrap_or(0.0);
+    let user_id = user_id.unwrap_or(0);
+    unsafe { ffi::igTableSetupColumn(label.as_ptr(), flags, init_width_or_weight, user_id) };
+    Ok(())
+}
+
+/// Locks the given number of columns and rows so they stay visible
+/// when scrolled.
+pub fn table_setup_scroll_freeze(cols: usize, rows: usize) {
+    unsafe { ffi::igTableSetupScrollFreeze(cols as c_int, rows as c_int) }
+}
+
 /// Adds a text widget.
 pub fn text(s: &str) -> Result<()> {
     let s = CString::new(s)?;
-    unsafe { ffi::igText(s.as_ptr()) };
+    unsafe { ffi::igText(c"%s".as_ptr(), s.as_ptr()) };
+    Ok(())
+}
+
+/// Text input callback data.
+pub struct InputTextCallbackData(*mut ffi::ImGuiInputTextCallbackData);
+
+impl InputTextCallbackData {
+    /// Returns the `INPUT_TEXT_FLAGS_CALLBACK_*` flag of the event
+    /// that triggered the callback.
+    pub fn event_flag(&self) -> i32 {
+        unsafe { (*self.0).EventFlag }
+    }
+
+    /// Returns the character being filtered. It is only available
+    /// with [`INPUT_TEXT_FLAGS_CALLBACK_CHAR_FILTER`].
+    pub fn event_char(&self) -> Option<char> {
+        char::from_u32(unsafe { (*self.0).EventChar } as u32)
+    }
+
+    /// Replaces the character being filtered. Characters outside of
+    /// the Basic Multilingual Plane are rejected, because Dear ImGui
+    /// stores them as UTF-16 code units.
+    pub fn set_event_char(&mut self, c: char) -> Result<()> {
+        let c = u16::try_from(c as u32).map_err(|_| Error::UnsupportedChar(c))?;
+        unsafe { (*self.0).EventChar = c };
+        Ok(())
+    }
+
+    /// Returns the current text. It is not available with
+    /// [`INPUT_TEXT_FLAGS_CALLBACK_CHAR_FILTER`].
+    pub fn text(&self) -> &str {
+        let (buf, len) = unsafe { ((*self.0).Buf, (*self.0).BufTextLen) };
+        if buf.is_null() {
+            return "";
+        }
+        let text = unsafe { slice::from_raw_parts(buf as *const u8, len as usize) };
+        std::str::from_utf8(text).unwrap_or("")
+    }
+
+    /// Returns the cursor position, as a byte offset.
+    pub fn cursor_pos(&self) -> usize {
+        unsafe { (*self.0).CursorPos as usize }
+    }
+
+    /// Sets the cursor position, as a byte offset.
+    pub fn set_cursor_pos(&mut self, pos: usize) {
+        unsafe { (*self.0).CursorPos = pos as c_int };
+    }
+
+    /// Returns the selected byte range.
+    pub fn selection(&self) -> (usize, usize) {
+        let (start, end) = unsafe { ((*self.0).SelectionStart, (*self.0).SelectionEnd) };
+        (start.min(end) as usize, start.max(end) as usize)
+    }
+
+    /// Deletes `len` bytes starting at byte offset `pos`.
+    pub fn delete_chars(&mut self, pos: usize, len: usize) {
+        unsafe { ffi::ImGuiInputTextCallbackData_DeleteChars(self.0, pos as c_int, len as c_int) }
+    }
+
+    /// Inserts `text` at byte offset `pos`.
+    pub fn insert_chars(&mut self, pos: usize, text: &str) {
+        let range = text.as_bytes().as_ptr_range();
+        unsafe {
+            ffi::ImGuiInputTextCallbackData_InsertChars(
+                self.0,
+                pos as c_int,
+                range.start as *const c_char,
+                range.end as *const c_char,
+            )
+        }
+    }
+}
+
+/// Adds a text widget with the given color.
+pub fn text_colored(col: Vec4<f32>, s: &str) -> Result<()> {
+    let s = CString::new(s)?;
+    unsafe { ffi::igTextColored(col.into(), c"%s".as_ptr(), s.as_ptr()) };
     Ok(())
 }
 
+/// Adds a text widget using the disabled text color.
+pub fn text_disabled(s: &str) -> Result<()> {
+    let s = CString::new(s)?;
+    unsafe { ffi::igTextDisabled(c"%s".as_ptr(), s.as_ptr()) };
+    Ok(())
+}
+
+/// Adds a text widget that wraps at the end of the window or column.
+pub fn text_wrapped(s: &str) -> Result<()> {
+    let s = CString::new(s)?;
+    unsafe { ffi::igTextWrapped(c"%s".as_ptr(), s.as_ptr()) };
+    Ok(())
+}
+
+/// Adds a tree node. The function returns whether the node is open,
+/// in which case its children must be added followed by a call to
+/// [`tree_pop`].
+pub fn tree_node(label: &str) -> Result<bool> {
+    let label = CString::new(label)?;
+    let open = unsafe { ffi::igTreeNode_Str(label.as_ptr()) };
+    Ok(open != 0)
+}
+
+/// Adds a tree node with the given `TREE_NODE_FLAGS_*` flags. See
+/// [`tree_node`].
+pub fn tree_node_ex(label: &str, flags: Option<i32>) -> Result<bool> {
+    let label = CString::new(label)?;
+    let flags = flags.unwrap_or(0);
+    let open = unsafe { ffi::igTreeNodeEx_Str(label.as_ptr(), flags) };
+    Ok(open != 0)
+}
+
+/// Pops a tree node. It must only be called if [`tree_node`] or
+/// [`tree_node_ex`] returned true.
+pub fn tree_pop() {
+    unsafe { ffi::igTreePop() }
+}
+
+/// Moves the content position back to the left. See [`indent`].
+pub fn unindent(indent_w: Option<f32>) {
+    let indent_w = indent_w.unwrap_or(0.0);
+    unsafe { ffi::igUnindent(indent_w) }
+}
+
+/// Adds a vertical slider float widget of the given size. See
+/// [`slider_float`].
+pub fn vslider_float(
+    label: &str,
+    size: Vec2<f32>,
+    v: &mut f32,
+    min: f32,
+    max: f32,
+    format: Option<&str>,
+    flags: Option<i32>,
+) -> Result<bool> {
+    let label = CString::new(label)?;
+    let format = format.map_or(CString::new("%.3f"), CString::new)?;
+    let flags = flags.unwrap_or(0);
+
+    let changed = unsafe {
+        ffi::igVSliderFloat(
+            label.as_ptr(),
+            size.into(),
+            v,
+            min,
+            max,
+            format.as_ptr(),
+            flags,
+        )
+    };
+    Ok(changed != 0)
+}
+
 /// IO state.
 pub struct IO(*mut ffi::ImGuiIO);
 
@@ -475,6 +2946,18 @@ impl IO {
         unsafe { (*self.0).LogFilename = filename };
         Ok(())
     }
+
+    /// Queues a new character input.
+    pub fn add_input_character(&mut self, c: char) {
+        unsafe { ffi::ImGuiIO_AddInputCharacter(self.0, c.into()) }
+    }
+
+    /// Queues new characters input from a UTF-8 string.
+    pub fn add_input_characters_utf8(&mut self, s: &str) -> Result<()> {
+        let s = CString::new(s)?;
+        unsafe { ffi::ImGuiIO_AddInputCharactersUTF8(self.0, s.as_ptr()) };
+        Ok(())
+    }
 }
 
 /// Returns the IO state.
@@ -509,6 +2992,177 @@ pub fn get_main_viewport() -> Viewport {
     Viewport(viewport)
 }
 
+/// Sorting specifications of a table.
+pub struct TableSortSpecs(*mut ffi::ImGuiTableSortSpecs);
+
+impl TableSortSpecs {
+    /// Returns the sorting specifications of every sorted column,
+    /// ordered by priority.
+    pub fn specs(&self) -> Vec<TableColumnSortSpecs> {
+        let (specs, count) = unsafe { ((*self.0).Specs, (*self.0).SpecsCount) };
+        if specs.is_null() || count <= 0 {
+            return Vec::new();
+        }
+        let specs = unsafe { slice::from_raw_parts(specs, count as usize) };
+        specs.iter().map(TableColumnSortSpecs::from).collect()
+    }
+
+    /// Returns whether the sorting specifications have changed since
+    /// last call to [`TableSortSpecs::clear_dirty`]. Items must be
+    /// sorted again if so.
+    pub fn is_dirty(&self) -> bool {
+        unsafe { (*self.0).SpecsDirty != 0 }
+    }
+
+    /// Marks the sorting specifications as handled.
+    pub fn clear_dirty(&mut self) {
+        unsafe { (*self.0).SpecsDirty = 0 };
+    }
+}
+
+/// Sorting specification of a table column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableColumnSortSpecs {
+    /// User ID of the column, as passed to [`table_setup_column`].
+    pub column_user_id: u32,
+
+    /// Index of the column.
+    pub column_index: usize,
+
+    /// Priority of the column when sorting by multiple columns. It is
+    /// 0 for the primary sort key.
+    pub sort_order: usize,
+
+    /// Sort direction.
+    pub sort_direction: SortDirection,
+}
+
+impl From<&ffi::ImGuiTableColumnSortSpecs> for TableColumnSortSpecs {
+    fn from(specs: &ffi::ImGuiTableColumnSortSpecs) -> TableColumnSortSpecs {
+        let sort_direction = match specs.SortDirection {
+            1 => SortDirection::Ascending,
+            2 => SortDirection::Descending,
+            _ => SortDirection::None,
+        };
+        TableColumnSortSpecs {
+            column_user_id: specs.ColumnUserID,
+            column_index: specs.ColumnIndex as usize,
+            sort_order: specs.SortOrder as usize,
+            sort_direction,
+        }
+    }
+}
+
+/// Returns the sorting specifications of the current table. It
+/// returns [`Option::None`] if the table is not sortable. It must be
+/// called after all columns have been set up.
+pub fn table_get_sort_specs() -> Option<TableSortSpecs> {
+    let specs = unsafe { ffi::igTableGetSortSpecs() };
+    if specs.is_null() {
+        return None;
+    }
+    Some(TableSortSpecs(specs))
+}
+
+/// Named window layout presets. Every preset stores the settings
+/// returned by [`save_ini_settings_to_memory`], so it is possible to
+/// switch between layouts (e.g. "Editing" and "Performance") at
+/// runtime.
+#[derive(Default)]
+pub struct LayoutPresets {
+    presets: BTreeMap<String, String>,
+    pending: Option<String>,
+}
+
+impl LayoutPresets {
+    /// Creates an empty collection of presets.
+    pub fn new() -> LayoutPresets {
+        LayoutPresets::default()
+    }
+
+    /// Captures the current layout as the preset `name`. If the
+    /// preset already exists, it is replaced.
+    pub fn capture(&mut self, name: &str) {
+        self.presets
+            .insert(name.to_string(), save_ini_settings_to_memory());
+    }
+
+    /// Applies the preset `name`. It returns false if the preset
+    /// does not exist. It must be called before [`new_frame`].
+    pub fn apply(&self, name: &str) -> bool {
+        match self.presets.get(name) {
+            Some(ini_data) => {
+                load_ini_settings_from_memory(ini_data);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the preset `name`. It returns false if the preset
+    /// does not exist.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.presets.remove(name).is_some()
+    }
+
+    /// Returns the names of the presets in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.presets.keys().map(|name| name.as_str())
+    }
+
+    /// Returns the settings stored in the preset `name`, so they can
+    /// be persisted.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.presets.get(name).map(|ini_data| ini_data.as_str())
+    }
+
+    /// Inserts a preset from previously persisted settings.
+    pub fn insert(&mut self, name: &str, ini_data: &str) {
+        self.presets.insert(name.to_string(), ini_data.to_string());
+    }
+
+    /// Adds a "Layout" menu, typically within a "Window" menu, that
+    /// lists the presets. Selecting a preset requests it to be
+    /// applied by [`LayoutPresets::apply_pending`] and the "Save"
+    /// sub-menu captures the current layout into an existing preset.
+    pub fn menu(&mut self) -> Result<()> {
+        if !begin_menu("Layout", !self.presets.is_empty())? {
+            return Ok(());
+        }
+        let mut capture = None;
+        for name in self.presets.keys() {
+            if menu_item(name, None, None, true)? {
+                self.pending = Some(name.clone());
+            }
+        }
+        separator();
+        if begin_menu("Save", true)? {
+            for name in self.presets.keys() {
+                if menu_item(name, None, None, true)? {
+                    capture = Some(name.clone());
+                }
+            }
+            end_menu();
+        }
+        end_menu();
+        if let Some(name) = capture {
+            self.capture(&name);
+        }
+        Ok(())
+    }
+
+    /// Applies the preset selected in [`LayoutPresets::menu`], if
+    /// any. It returns whether a preset has been applied. Like
+    /// [`LayoutPresets::apply`], it must be called before
+    /// [`new_frame`].
+    pub fn apply_pending(&mut self) -> bool {
+        match self.pending.take() {
+            Some(name) => self.apply(&name),
+            None => false,
+        }
+    }
+}
+
 /// Dear ImGui GLFW backend.
 pub mod glfw {
     use super::{Error, Result};
@@ -538,6 +3192,29 @@ pub mod glfw {
         }
     }
 
+    /// Forwards the Unicode characters entered in `window`, including
+    /// the ones composed by input methods, to Dear ImGui as UTF-8. It
+    /// replaces the character callback of the window, so it must be
+    /// called after [`init_for_opengl`]. When the backend has installed
+    /// its callbacks, this prevents characters from being queued
+    /// twice. `on_char` is called for every character after it has
+    /// been queued, so the application can still handle them.
+    pub fn install_char_callback<F>(window: crate::glfw::Window, mut on_char: Option<F>)
+    where
+        F: FnMut(crate::glfw::Window, char) + Send + 'static,
+    {
+        let callback = move |window, c: char| {
+            let mut buf = [0; 4];
+            // The only possible error is an interior nul byte, which
+            // is not a printable character anyway.
+            let _ = super::get_io().add_input_characters_utf8(c.encode_utf8(&mut buf));
+            if let Some(on_char) = on_char.as_mut() {
+                on_char(window, c);
+            }
+        };
+        crate::glfw::set_char_callback(window, Some(callback));
+    }
+
     /// Starts a frame.
     pub fn new_frame() {
         unsafe { ffi::ImGui_ImplGlfw_NewFrame() }
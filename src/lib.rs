@@ -2,11 +2,14 @@
 
 use std::{error, fmt, ops, result};
 
+pub mod curve;
 pub mod gl;
 pub mod glfw;
+pub mod glsl;
 pub mod imgui;
 mod macros;
 pub mod stb_image;
+pub mod time;
 
 /// A specialized result type.
 pub type Result<T> = result::Result<T, Error>;
@@ -20,11 +23,27 @@ pub enum Error {
     /// OpenGL error.
     Gl(gl::Error),
 
+    /// GLSL error.
+    Glsl(glsl::Error),
+
     /// Dear ImGui error.
     ImGui(imgui::Error),
 
     /// stb_image error.
     StbImage(stb_image::Error),
+
+    /// Error annotated with the operation that was being performed
+    /// when it happened. Only the context is displayed; the wrapped
+    /// error is returned by [`error::Error::source`].
+    Context(String, Box<Error>),
+}
+
+impl Error {
+    /// Wraps `inner` with a description of the operation that failed
+    /// (e.g. "compiling bloom.frag").
+    pub fn with_context<C: Into<String>, E: Into<Error>>(context: C, inner: E) -> Error {
+        Error::Context(context.into(), Box::new(inner.into()))
+    }
 }
 
 impl From<glfw::Error> for Error {
@@ -39,6 +58,12 @@ impl From<gl::Error> for Error {
     }
 }
 
+impl From<glsl::Error> for Error {
+    fn from(err: glsl::Error) -> Error {
+        Error::Glsl(err)
+    }
+}
+
 impl From<imgui::Error> for Error {
     fn from(err: imgui::Error) -> Error {
         Error::ImGui(err)
@@ -56,13 +81,43 @@ impl fmt::Display for Error {
         match self {
             Error::Glfw(err) => write!(f, "GLFW error: {err}"),
             Error::Gl(err) => write!(f, "OpenGL error: {err}"),
+            Error::Glsl(err) => write!(f, "GLSL error: {err}"),
             Error::ImGui(err) => write!(f, "Dear ImGui error: {err}"),
             Error::StbImage(err) => write!(f, "stb_image error: {err}"),
+            Error::Context(context, _) => write!(f, "{context}"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Context(_, err) => Some(err.as_ref()),
+            _ => None,
         }
     }
 }
 
-impl error::Error for Error {}
+/// Extension trait used to add context to the errors returned by the
+/// crate.
+pub trait Context<T> {
+    /// Wraps the error, if any, with the provided context.
+    fn context<C: Into<String>>(self, context: C) -> Result<T>;
+
+    /// Wraps the error, if any, with the context returned by `f`,
+    /// which is only evaluated in case of error.
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, f: F) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> Context<T> for result::Result<T, E> {
+    fn context<C: Into<String>>(self, context: C) -> Result<T> {
+        self.map_err(|err| Error::with_context(context, err))
+    }
+
+    fn with_context<C: Into<String>, F: FnOnce() -> C>(self, f: F) -> Result<T> {
+        self.map_err(|err| Error::with_context(f(), err))
+    }
+}
 
 macro_rules! define_vec {
     ($name:ident, $n:expr) => {
@@ -6,6 +6,7 @@ macro_rules! define_enum {
     })+) => {
         $(
         #[doc = concat!($enum_doc, ".")]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
         $vis enum $enum_name {
             $(
             #[doc = concat!($variant_doc, ".")]
@@ -1,11 +1,12 @@
 //! stb_image bindings.
 
 use std::{
+    cell::Cell,
     error,
-    ffi::{c_int, c_void, CString, NulError},
+    ffi::{c_int, c_void, CStr, CString, NulError},
     fmt,
     path::Path,
-    result, slice,
+    result, slice, thread,
 };
 
 #[allow(non_camel_case_types)]
@@ -16,7 +17,9 @@ mod ffi {
 
     #[link(name = "stb_image")]
     extern "C" {
+        pub fn stbi_failure_reason() -> *const c_char;
         pub fn stbi_set_flip_vertically_on_load(flag_true_if_should_flip: c_int);
+        pub fn stbi_set_flip_vertically_on_load_thread(flag_true_if_should_flip: c_int);
         pub fn stbi_load(
             filename: *const c_char,
             x: *mut c_int,
@@ -43,13 +46,16 @@ pub type Result<T> = result::Result<T, Error>;
 #[derive(Debug)]
 pub enum Error {
     /// Failed to load image.
-    Load,
+    Load(String),
 
     /// Invalid UTF-8 string.
     InvalidUtf8,
 
     /// Invalid C string.
     InvalidCString(NulError),
+
+    /// Invalid number of components per pixel.
+    InvalidChannels(usize),
 }
 
 impl From<NulError> for Error {
@@ -61,9 +67,10 @@ impl From<NulError> for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Error::Load => write!(f, "failed to load image"),
+            Error::Load(reason) => write!(f, "failed to load image: {reason}"),
             Error::InvalidUtf8 => write!(f, "invalid UTF-8 string"),
             Error::InvalidCString(err) => write!(f, "invalid C string: {err}"),
+            Error::InvalidChannels(n) => write!(f, "invalid number of channels: {n}"),
         }
     }
 }
@@ -71,87 +78,226 @@ impl fmt::Display for Error {
 impl error::Error for Error {}
 
 /// Flips the image vertically, so the first pixel in the output array
-/// is the bottom left.
+/// is the bottom left. This setting is global and applies to all
+/// threads that have not called [`set_flip_vertically_on_load_thread`].
 pub fn set_flip_vertically_on_load(flip: bool) {
     let flip = if flip { 1 } else { 0 };
     unsafe { ffi::stbi_set_flip_vertically_on_load(flip) }
 }
 
+thread_local! {
+    static FLIP_VERTICALLY_ON_LOAD_THREAD: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Same as [`set_flip_vertically_on_load`], but it only affects the
+/// calling thread. It takes precedence over the global setting.
+pub fn set_flip_vertically_on_load_thread(flip: bool) {
+    FLIP_VERTICALLY_ON_LOAD_THREAD.set(Some(flip));
+    let flip = if flip { 1 } else { 0 };
+    unsafe { ffi::stbi_set_flip_vertically_on_load_thread(flip) }
+}
+
+/// Returns the reason of the last failure in the calling thread.
+fn failure_reason() -> String {
+    let reason = unsafe { ffi::stbi_failure_reason() };
+    if reason.is_null() {
+        return "unknown reason".to_string();
+    }
+    unsafe { CStr::from_ptr(reason) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Image import options. They allow to express per-asset import
+/// settings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ImportOptions {
+    /// Flips the image vertically after decoding. It is applied on top
+    /// of the flip settings of stb_image.
+    pub flip_vertically: bool,
+
+    /// Number of components per pixel the image is converted to. It
+    /// must be in the range `1..=4`. If `None`, the number of
+    /// components in the file is kept.
+    pub channels: Option<usize>,
+
+    /// Multiplies the color components by the alpha component.
+    pub premultiply_alpha: bool,
+
+    /// Generates mipmaps when the image is uploaded to a texture.
+    pub generate_mipmaps: bool,
+
+    /// The color components are sRGB encoded.
+    pub srgb: bool,
+}
+
 /// Represents an image.
 pub struct Image {
     pixels: Vec<u8>,
     width: usize,
     height: usize,
     channels: usize,
+    options: ImportOptions,
 }
 
 impl Image {
     /// Parses an image from file.
     pub fn load<P: AsRef<Path>>(filename: P) -> Result<Image> {
-        let filename = CString::new(filename.as_ref().to_str().ok_or(Error::InvalidUtf8)?)?;
-
-        let mut c_width: c_int = 0;
-        let mut c_height: c_int = 0;
-        let mut c_channels: c_int = 0;
-
-        let retval = unsafe {
-            ffi::stbi_load(
-                filename.as_ptr(),
-                &mut c_width,
-                &mut c_height,
-                &mut c_channels,
-                0,
-            )
-        };
-        if retval.is_null() {
-            return Err(Error::Load);
-        }
-
-        let len = (c_width * c_height * c_channels) as usize;
-        let pixels = unsafe { slice::from_raw_parts(retval, len).to_vec() };
+        Image::load_with_options(filename, &ImportOptions::default())
+    }
 
-        unsafe { ffi::stbi_image_free(retval as *mut c_void) };
+    /// Parses an image from file using the specified import options.
+    pub fn load_with_options<P: AsRef<Path>>(
+        filename: P,
+        options: &ImportOptions,
+    ) -> Result<Image> {
+        let filename = CString::new(filename.as_ref().to_str().ok_or(Error::InvalidUtf8)?)?;
 
-        Ok(Image {
-            pixels,
-            width: c_width as usize,
-            height: c_height as usize,
-            channels: c_channels as usize,
+        Image::decode(options, |x, y, channels_in_file, desired_channels| unsafe {
+            ffi::stbi_load(filename.as_ptr(), x, y, channels_in_file, desired_channels)
         })
     }
 
     /// Parses an image from buffer in memory.
     pub fn load_from_memory<B: AsRef<[u8]>>(buffer: B) -> Result<Image> {
-        let buffer = buffer.as_ref();
+        Image::load_from_memory_with_options(buffer, &ImportOptions::default())
+    }
 
-        let mut c_width: c_int = 0;
-        let mut c_height: c_int = 0;
-        let mut c_channels: c_int = 0;
+    /// Parses an image from buffer in memory using the specified
+    /// import options.
+    pub fn load_from_memory_with_options<B: AsRef<[u8]>>(
+        buffer: B,
+        options: &ImportOptions,
+    ) -> Result<Image> {
+        let buffer = buffer.as_ref();
 
-        let retval = unsafe {
+        Image::decode(options, |x, y, channels_in_file, desired_channels| unsafe {
             ffi::stbi_load_from_memory(
                 buffer.as_ptr(),
                 buffer.len() as c_int,
-                &mut c_width,
-                &mut c_height,
-                &mut c_channels,
-                0,
+                x,
+                y,
+                channels_in_file,
+                desired_channels,
             )
+        })
+    }
+
+    /// Decodes an image calling `load` and applies the import
+    /// options.
+    fn decode<F>(options: &ImportOptions, load: F) -> Result<Image>
+    where
+        F: FnOnce(*mut c_int, *mut c_int, *mut c_int, c_int) -> *mut ffi::stbi_uc,
+    {
+        let mut c_width: c_int = 0;
+        let mut c_height: c_int = 0;
+        let mut c_channels: c_int = 0;
+
+        let desired_channels = match options.channels {
+            Some(n @ 1..=4) => n as c_int,
+            Some(n) => return Err(Error::InvalidChannels(n)),
+            None => 0,
         };
+        let retval = load(
+            &mut c_width,
+            &mut c_height,
+            &mut c_channels,
+            desired_channels,
+        );
         if retval.is_null() {
-            return Err(Error::Load);
+            return Err(Error::Load(failure_reason()));
+        }
+
+        // stb_image returns the number of components in the file,
+        // even if the image was converted.
+        if desired_channels != 0 {
+            c_channels = desired_channels;
         }
 
-        let len = (c_width * c_height * c_channels) as usize;
-        let pixels = unsafe { slice::from_raw_parts(retval, len).to_vec() };
+        let len = (c_width as usize)
+            .checked_mul(c_height as usize)
+            .and_then(|n| n.checked_mul(c_channels as usize));
+        let pixels = len.map(|len| unsafe { slice::from_raw_parts(retval, len).to_vec() });
 
         unsafe { ffi::stbi_image_free(retval as *mut c_void) };
 
-        Ok(Image {
+        let pixels = pixels.ok_or_else(|| Error::Load("image too large".to_string()))?;
+
+        let mut image = Image {
             pixels,
             width: c_width as usize,
             height: c_height as usize,
             channels: c_channels as usize,
+            options: *options,
+        };
+        if options.flip_vertically {
+            image.flip_vertically();
+        }
+        if options.premultiply_alpha {
+            image.premultiply_alpha();
+        }
+        Ok(image)
+    }
+
+    /// Flips the pixel rows.
+    fn flip_vertically(&mut self) {
+        let stride = self.width * self.channels;
+        if stride == 0 {
+            return;
+        }
+        let (mut top, mut bottom) = (0, self.height);
+        while top + 1 < bottom {
+            bottom -= 1;
+            let (head, tail) = self.pixels.split_at_mut(bottom * stride);
+            head[top * stride..(top + 1) * stride].swap_with_slice(&mut tail[..stride]);
+            top += 1;
+        }
+    }
+
+    /// Multiplies the color components by the alpha component. Images
+    /// without alpha channel are not modified.
+    fn premultiply_alpha(&mut self) {
+        if self.channels != 2 && self.channels != 4 {
+            return;
+        }
+        for pixel in self.pixels.chunks_exact_mut(self.channels) {
+            let (alpha, color) = pixel.split_last_mut().unwrap();
+            for c in color {
+                *c = ((*c as u16 * *alpha as u16 + 127) / 255) as u8;
+            }
+        }
+    }
+
+    /// Parses the images in `filenames` in parallel. The results are
+    /// returned in the same order as `filenames`. The thread-local
+    /// flip setting of the calling thread is honored.
+    pub fn load_many<P: AsRef<Path> + Sync>(filenames: &[P]) -> Vec<Result<Image>> {
+        if filenames.is_empty() {
+            return Vec::new();
+        }
+
+        let flip = FLIP_VERTICALLY_ON_LOAD_THREAD.get();
+        let nthreads = thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(filenames.len());
+        let chunk_size = filenames.len().div_ceil(nthreads);
+
+        thread::scope(|s| {
+            let handles = filenames
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    s.spawn(move || {
+                        if let Some(flip) = flip {
+                            set_flip_vertically_on_load_thread(flip);
+                        }
+                        chunk.iter().map(Image::load).collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("image decoding thread panicked"))
+                .collect()
         })
     }
 
@@ -174,4 +320,9 @@ impl Image {
     pub fn channels(&self) -> usize {
         self.channels
     }
+
+    /// Returns the options the image was imported with.
+    pub fn options(&self) -> &ImportOptions {
+        &self.options
+    }
 }
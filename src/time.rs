@@ -0,0 +1,205 @@
+//! Frame timing utilities.
+
+use std::collections::VecDeque;
+
+use crate::glfw;
+
+/// Frame pacing monitor. It records the interval between frames,
+/// measures its jitter and detects missed vsync windows, which helps
+/// diagnosing stutter caused by background work.
+pub struct PacingMonitor {
+    target_interval: f64,
+    capacity: usize,
+    intervals: VecDeque<f64>,
+    last_time: Option<f64>,
+    missed_windows: u64,
+}
+
+impl PacingMonitor {
+    /// Creates a pacing monitor for a display with the specified
+    /// `refresh_rate` in Hz. Statistics are computed over the last
+    /// `capacity` frames.
+    pub fn new(refresh_rate: f64, capacity: usize) -> PacingMonitor {
+        PacingMonitor {
+            target_interval: 1.0 / refresh_rate,
+            capacity: capacity.max(1),
+            intervals: VecDeque::with_capacity(capacity.max(1)),
+            last_time: None,
+            missed_windows: 0,
+        }
+    }
+
+    /// Records a frame presented at `time`, in seconds. It returns
+    /// the number of vsync windows missed since the previous frame.
+    pub fn record(&mut self, time: f64) -> u32 {
+        let Some(last_time) = self.last_time.replace(time) else {
+            return 0;
+        };
+
+        let interval = time - last_time;
+        if self.intervals.len() == self.capacity {
+            self.intervals.pop_front();
+        }
+        self.intervals.push_back(interval);
+
+        let missed = ((interval / self.target_interval).round() as u32).saturating_sub(1);
+        self.missed_windows += u64::from(missed);
+        missed
+    }
+
+    /// Records a frame presented now, according to the GLFW timer.
+    /// It returns the number of vsync windows missed since the
+    /// previous frame.
+    pub fn frame(&mut self) -> u32 {
+        self.record(glfw::get_time())
+    }
+
+    /// Returns the expected interval between frames in seconds.
+    pub fn target_interval(&self) -> f64 {
+        self.target_interval
+    }
+
+    /// Returns the recorded frame intervals in seconds, from oldest
+    /// to newest.
+    pub fn intervals(&self) -> impl Iterator<Item = f64> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    /// Returns the mean frame interval in seconds.
+    pub fn mean_interval(&self) -> f64 {
+        if self.intervals.is_empty() {
+            return 0.0;
+        }
+        self.intervals.iter().sum::<f64>() / self.intervals.len() as f64
+    }
+
+    /// Returns the longest recorded frame interval in seconds.
+    pub fn max_interval(&self) -> f64 {
+        self.intervals.iter().copied().fold(0.0, f64::max)
+    }
+
+    /// Returns the frame interval jitter, computed as the standard
+    /// deviation of the recorded intervals, in seconds.
+    pub fn jitter(&self) -> f64 {
+        if self.intervals.is_empty() {
+            return 0.0;
+        }
+        let mean = self.mean_interval();
+        let variance = self
+            .intervals
+            .iter()
+            .map(|interval| (interval - mean).powi(2))
+            .sum::<f64>()
+            / self.intervals.len() as f64;
+        variance.sqrt()
+    }
+
+    /// Returns the total number of missed vsync windows.
+    pub fn missed_windows(&self) -> u64 {
+        self.missed_windows
+    }
+
+    /// Clears the recorded statistics.
+    pub fn reset(&mut self) {
+        self.intervals.clear();
+        self.last_time = None;
+        self.missed_windows = 0;
+    }
+}
+
+/// Frame clock. It produces the time elapsed between frames, the
+/// total elapsed time and a smoothed frames per second estimate.
+pub struct FrameClock {
+    smoothing: f64,
+    start_time: Option<f64>,
+    last_time: Option<f64>,
+    delta: f64,
+    elapsed: f64,
+    fps: f64,
+    frames: u64,
+}
+
+impl Default for FrameClock {
+    fn default() -> FrameClock {
+        FrameClock::new()
+    }
+}
+
+impl FrameClock {
+    /// Creates a frame clock with the default FPS smoothing factor of
+    /// 0.1.
+    pub fn new() -> FrameClock {
+        FrameClock::with_smoothing(0.1)
+    }
+
+    /// Creates a frame clock with the specified FPS smoothing factor,
+    /// in the `(0, 1]` range. Lower values produce a more stable
+    /// estimate that reacts slower to changes. A factor of 1 disables
+    /// smoothing.
+    pub fn with_smoothing(smoothing: f64) -> FrameClock {
+        FrameClock {
+            smoothing: smoothing.clamp(f64::EPSILON, 1.0),
+            start_time: None,
+            last_time: None,
+            delta: 0.0,
+            elapsed: 0.0,
+            fps: 0.0,
+            frames: 0,
+        }
+    }
+
+    /// Records a frame started at `time`, in seconds. It returns the
+    /// time elapsed since the previous frame, which is zero for the
+    /// first frame.
+    pub fn tick_at(&mut self, time: f64) -> f64 {
+        let start_time = *self.start_time.get_or_insert(time);
+        let last_time = self.last_time.replace(time).unwrap_or(time);
+
+        self.delta = (time - last_time).max(0.0);
+        self.elapsed = time - start_time;
+        self.frames += 1;
+
+        if self.delta > 0.0 {
+            let fps = 1.0 / self.delta;
+            self.fps = if self.fps == 0.0 {
+                fps
+            } else {
+                self.fps + self.smoothing * (fps - self.fps)
+            };
+        }
+        self.delta
+    }
+
+    /// Records a frame started now, according to the GLFW timer. It
+    /// returns the time elapsed since the previous frame.
+    pub fn tick(&mut self) -> f64 {
+        self.tick_at(glfw::get_time())
+    }
+
+    /// Returns the time elapsed between the last two frames in
+    /// seconds.
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Returns the time elapsed since the first frame in seconds.
+    pub fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    /// Returns the smoothed frames per second estimate.
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    /// Returns the number of recorded frames.
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// Resets the clock. The next recorded frame becomes the first
+    /// one.
+    pub fn reset(&mut self) {
+        *self = FrameClock::with_smoothing(self.smoothing);
+    }
+}